@@ -1,6 +1,7 @@
 error_chain! {
     foreign_links {
         Fmt(::std::fmt::Error);
+        Io(::std::io::Error);
     }
 
     errors {
@@ -12,5 +13,50 @@ error_chain! {
 
         VariableUnderflow {
         }
+
+        NoPublicType(package: String) {
+            description("file has no public top-level type")
+            display("file in package `{}` has no public top-level type", package)
+        }
+
+        MultiplePublicTypes(package: String, names: Vec<String>) {
+            description("file has multiple public top-level types")
+            display("file in package `{}` has multiple public top-level types: {}", package, names.join(", "))
+        }
+
+        DuplicateFile(path: String) {
+            description("multiple files map to the same path")
+            display("multiple files map to the same path: {}", path)
+        }
+
+        UnknownParameter(name: String) {
+            description("documented parameter does not exist")
+            display("documented parameter `{}` does not exist", name)
+        }
+
+        UnknownThrows(name: String) {
+            description("documented exception is not thrown")
+            display("documented exception `{}` is not thrown", name)
+        }
+
+        DuplicateExport(name: String) {
+            description("name is exported more than once")
+            display("`{}` is exported more than once", name)
+        }
+
+        ImportCollision(name: String) {
+            description("local name is bound by multiple imports")
+            display("local name `{}` is bound by multiple imports", name)
+        }
+
+        InvalidStatement(reason: String) {
+            description("statement is not valid")
+            display("statement is not valid: {}", reason)
+        }
+
+        InvalidFString(expression: String) {
+            description("f-string expression can't be quoted")
+            display("f-string expression `{}` contains a backslash or both kinds of quotes", expression)
+        }
     }
 }
@@ -6,6 +6,8 @@ mod element_formatter;
 mod elements;
 mod statement;
 mod variable_format;
+mod visitor;
+mod walk;
 pub mod element;
 pub mod errors;
 
@@ -14,5 +16,7 @@ pub use self::element_formatter::*;
 pub use self::element::*;
 pub use self::statement::*;
 pub use self::variable_format::*;
+pub use self::visitor::*;
 pub use self::elements::*;
+pub use self::walk::*;
 pub(crate) use self::errors::*;
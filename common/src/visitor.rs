@@ -0,0 +1,141 @@
+use super::*;
+
+/// Visit a tree of elements.
+///
+/// Every method defaults to walking its children, so implementations only override what they
+/// are interested in, and call the matching `walk_*` function to keep descending.
+pub trait Visitor<Var>
+where
+    Var: VariableFormat,
+{
+    fn visit_element(&mut self, element: &Element<Var>) {
+        walk_element(self, element);
+    }
+
+    fn visit_elements(&mut self, elements: &Elements<Var>) {
+        walk_elements(self, elements);
+    }
+
+    fn visit_statement(&mut self, statement: &Statement<Var>) {
+        walk_statement(self, statement);
+    }
+
+    fn visit_literal(&mut self, _literal: &str) {}
+
+    fn visit_variable(&mut self, _variable: &Var) {}
+}
+
+/// Visit a tree of elements, permitting it to be rewritten.
+///
+/// Elements can be removed by overriding `visit_elements_mut` or by replacing them in
+/// `visit_element_mut`.
+pub trait VisitorMut<Var>
+where
+    Var: VariableFormat,
+{
+    fn visit_element_mut(&mut self, element: &mut Element<Var>) {
+        walk_element_mut(self, element);
+    }
+
+    fn visit_elements_mut(&mut self, elements: &mut Elements<Var>) {
+        walk_elements_mut(self, elements);
+    }
+
+    fn visit_statement_mut(&mut self, statement: &mut Statement<Var>) {
+        walk_statement_mut(self, statement);
+    }
+
+    fn visit_literal_mut(&mut self, _literal: &mut String) {}
+
+    fn visit_variable_mut(&mut self, _variable: &mut Var) {}
+}
+
+pub fn walk_element<V, Var>(visitor: &mut V, element: &Element<Var>)
+where
+    V: Visitor<Var> + ?Sized,
+    Var: VariableFormat,
+{
+    match *element {
+        Element::Push(ref statement) |
+        Element::Concat(ref statement) => {
+            visitor.visit_statement(statement);
+        }
+        Element::Literal(ref literal) => {
+            visitor.visit_literal(literal);
+        }
+        Element::Inner(ref elements) => {
+            for element in elements {
+                visitor.visit_element(element);
+            }
+        }
+        Element::Nested(ref element) => {
+            visitor.visit_element(element);
+        }
+        Element::Spacing => {}
+    }
+}
+
+pub fn walk_elements<V, Var>(visitor: &mut V, elements: &Elements<Var>)
+where
+    V: Visitor<Var> + ?Sized,
+    Var: VariableFormat,
+{
+    for element in &elements.elements {
+        visitor.visit_element(element);
+    }
+}
+
+pub fn walk_statement<V, Var>(visitor: &mut V, statement: &Statement<Var>)
+where
+    V: Visitor<Var> + ?Sized,
+    Var: VariableFormat,
+{
+    for part in &statement.parts {
+        visitor.visit_variable(part);
+    }
+}
+
+pub fn walk_element_mut<V, Var>(visitor: &mut V, element: &mut Element<Var>)
+where
+    V: VisitorMut<Var> + ?Sized,
+    Var: VariableFormat,
+{
+    match *element {
+        Element::Push(ref mut statement) |
+        Element::Concat(ref mut statement) => {
+            visitor.visit_statement_mut(statement);
+        }
+        Element::Literal(ref mut literal) => {
+            visitor.visit_literal_mut(literal);
+        }
+        Element::Inner(ref mut elements) => {
+            for element in elements {
+                visitor.visit_element_mut(element);
+            }
+        }
+        Element::Nested(ref mut element) => {
+            visitor.visit_element_mut(element);
+        }
+        Element::Spacing => {}
+    }
+}
+
+pub fn walk_elements_mut<V, Var>(visitor: &mut V, elements: &mut Elements<Var>)
+where
+    V: VisitorMut<Var> + ?Sized,
+    Var: VariableFormat,
+{
+    for element in &mut elements.elements {
+        visitor.visit_element_mut(element);
+    }
+}
+
+pub fn walk_statement_mut<V, Var>(visitor: &mut V, statement: &mut Statement<Var>)
+where
+    V: VisitorMut<Var> + ?Sized,
+    Var: VariableFormat,
+{
+    for part in &mut statement.parts {
+        visitor.visit_variable_mut(part);
+    }
+}
@@ -0,0 +1,63 @@
+use super::*;
+
+/// Walk all variables which are part of a tree of elements, in the order they appear.
+///
+/// Backends use this to find what a tree references, like the names which have to be imported,
+/// without having to repeat how each element is traversed.
+pub trait Walk<Var>
+where
+    Var: VariableFormat,
+{
+    fn walk<F>(&self, callback: &mut F)
+    where
+        F: FnMut(&Var);
+}
+
+/// Visitor calling back for every variable.
+struct Callback<'a, F: 'a>(&'a mut F);
+
+impl<'a, F, Var> Visitor<Var> for Callback<'a, F>
+where
+    F: FnMut(&Var),
+    Var: VariableFormat,
+{
+    fn visit_variable(&mut self, variable: &Var) {
+        (self.0)(variable);
+    }
+}
+
+impl<Var> Walk<Var> for Element<Var>
+where
+    Var: VariableFormat,
+{
+    fn walk<F>(&self, callback: &mut F)
+    where
+        F: FnMut(&Var),
+    {
+        Callback(callback).visit_element(self);
+    }
+}
+
+impl<Var> Walk<Var> for Elements<Var>
+where
+    Var: VariableFormat,
+{
+    fn walk<F>(&self, callback: &mut F)
+    where
+        F: FnMut(&Var),
+    {
+        Callback(callback).visit_elements(self);
+    }
+}
+
+impl<Var> Walk<Var> for Statement<Var>
+where
+    Var: VariableFormat,
+{
+    fn walk<F>(&self, callback: &mut F)
+    where
+        F: FnMut(&Var),
+    {
+        Callback(callback).visit_statement(self);
+    }
+}
@@ -40,30 +40,28 @@ impl From<ClassType> for AnnotationSpec {
     }
 }
 
-impl From<AnnotationSpec> for Element {
-    fn from(value: AnnotationSpec) -> Element {
-        let mut elements = Elements::new();
-
+impl From<AnnotationSpec> for Statement {
+    fn from(value: AnnotationSpec) -> Statement {
         let mut annotation = Statement::new();
         annotation.push("@");
         annotation.push(value.ty);
 
         if !value.arguments.is_empty() {
-            let mut open = Statement::new();
-
             let arguments: Statement = value.arguments.into();
 
-            open.push(annotation);
-            open.push("(");
-            open.push(arguments.join(", "));
-            open.push(")");
-
-            elements.push(open);
-        } else {
-            elements.push(annotation);
+            annotation.push("(");
+            annotation.push(arguments.join(", "));
+            annotation.push(")");
         }
 
-        elements.into()
+        annotation
+    }
+}
+
+impl From<AnnotationSpec> for Element {
+    fn from(value: AnnotationSpec) -> Element {
+        let annotation: Statement = value.into();
+        annotation.into()
     }
 }
 
@@ -26,6 +26,15 @@ impl ArgumentSpec {
     }
 }
 
+impl<'a, T> From<&'a T> for ArgumentSpec
+where
+    T: Into<ArgumentSpec> + Clone,
+{
+    fn from(value: &'a T) -> ArgumentSpec {
+        value.clone().into()
+    }
+}
+
 impl From<ArgumentSpec> for Variable {
     fn from(value: ArgumentSpec) -> Variable {
         Variable::Literal(value.name)
@@ -36,7 +45,8 @@ impl From<ArgumentSpec> for Statement {
     fn from(value: ArgumentSpec) -> Statement {
         let mut s = Statement::new();
 
-        for a in &value.annotations {
+        for a in value.annotations {
+            let a: Statement = a.into();
             s.push(a);
             s.push(" ");
         }
@@ -43,6 +43,7 @@ impl From<ClassSpec> for Element {
         }
 
         let mut open = Statement::new();
+        open.push(Variable::Declaration(value.declared()));
 
         if !value.modifiers.is_empty() {
             open.push(value.modifiers);
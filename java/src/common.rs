@@ -43,3 +43,29 @@ where
 
     s
 }
+
+/// Build a javadoc comment out of the given lines.
+pub fn javadoc<I>(lines: I) -> Elements
+where
+    I: IntoIterator<Item = Statement>,
+{
+    let mut elements = Elements::new();
+
+    elements.push("/**");
+
+    for line in lines {
+        let mut s = Statement::new();
+
+        if line.parts.is_empty() {
+            s.push(" *");
+        } else {
+            s.push(" * ");
+            s.push(line);
+        }
+
+        elements.push(s);
+    }
+
+    elements.push(" */");
+    elements
+}
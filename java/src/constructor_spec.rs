@@ -3,6 +3,7 @@ use super::*;
 #[derive(Debug, Clone)]
 pub struct ConstructorSpec {
     pub modifiers: Modifiers,
+    pub javadoc: Option<JavadocSpec>,
     pub annotations: Vec<AnnotationSpec>,
     pub arguments: Vec<ArgumentSpec>,
     pub throws: Vec<ClassType>,
@@ -13,6 +14,7 @@ impl ConstructorSpec {
     pub fn new(modifiers: Modifiers) -> ConstructorSpec {
         ConstructorSpec {
             modifiers: modifiers,
+            javadoc: None,
             annotations: Vec::new(),
             arguments: Vec::new(),
             throws: Vec::new(),
@@ -20,6 +22,19 @@ impl ConstructorSpec {
         }
     }
 
+    pub fn javadoc(&mut self, javadoc: JavadocSpec) {
+        self.javadoc = Some(javadoc);
+    }
+
+    /// Check that the javadoc, if any, only documents arguments and exceptions of this constructor.
+    pub fn validate_javadoc(&self) -> Result<()> {
+        if let Some(ref javadoc) = self.javadoc {
+            javadoc.validate(&self.arguments, &self.throws)?;
+        }
+
+        Ok(())
+    }
+
     pub fn push_annotation<A>(&mut self, annotation: A)
     where
         A: Into<AnnotationSpec>,
@@ -53,6 +68,10 @@ impl ConstructorSpec {
 
         let mut open = Statement::new();
 
+        if let Some(javadoc) = self.javadoc {
+            elements.push(javadoc);
+        }
+
         for a in self.annotations {
             elements.push(a);
         }
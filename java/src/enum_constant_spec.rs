@@ -0,0 +1,96 @@
+use super::*;
+
+/// A single constant in an enum, like `FOO("foo") { ... }`.
+#[derive(Debug, Clone)]
+pub struct EnumConstantSpec {
+    pub name: String,
+    pub annotations: Vec<AnnotationSpec>,
+    pub javadoc: Vec<Statement>,
+    pub arguments: Vec<Statement>,
+    pub elements: Elements,
+}
+
+impl EnumConstantSpec {
+    pub fn new(name: &str) -> EnumConstantSpec {
+        EnumConstantSpec {
+            name: name.to_owned(),
+            annotations: Vec::new(),
+            javadoc: Vec::new(),
+            arguments: Vec::new(),
+            elements: Elements::new(),
+        }
+    }
+
+    pub fn push_annotation<A>(&mut self, annotation: A)
+    where
+        A: Into<AnnotationSpec>,
+    {
+        self.annotations.push(annotation.into());
+    }
+
+    pub fn push_javadoc<S>(&mut self, line: S)
+    where
+        S: Into<Statement>,
+    {
+        self.javadoc.push(line.into());
+    }
+
+    pub fn push_argument<S>(&mut self, argument: S)
+    where
+        S: Into<Statement>,
+    {
+        self.arguments.push(argument.into());
+    }
+
+    /// Push an element to the anonymous body of this constant.
+    pub fn push<E>(&mut self, element: E)
+    where
+        E: Into<Element>,
+    {
+        self.elements.push(element);
+    }
+}
+
+impl<'a, T> From<&'a T> for EnumConstantSpec
+where
+    T: Into<EnumConstantSpec> + Clone,
+{
+    fn from(value: &'a T) -> EnumConstantSpec {
+        value.clone().into()
+    }
+}
+
+impl From<EnumConstantSpec> for Element {
+    fn from(value: EnumConstantSpec) -> Element {
+        let mut elements = Elements::new();
+
+        if !value.javadoc.is_empty() {
+            elements.push(javadoc(value.javadoc));
+        }
+
+        for a in value.annotations {
+            elements.push(a);
+        }
+
+        let mut open = Statement::new();
+        open.push(value.name);
+
+        if !value.arguments.is_empty() {
+            open.push("(");
+            open.push(join_statements(value.arguments, ", "));
+            open.push(")");
+        }
+
+        if value.elements.is_empty() {
+            elements.push(open);
+        } else {
+            open.push(" {");
+
+            elements.push(open);
+            elements.push_nested(value.elements.join(Spacing));
+            elements.push("}");
+        }
+
+        elements.into()
+    }
+}
@@ -45,6 +45,7 @@ impl From<EnumSpec> for Element {
         // opening statement
         {
             let mut open = Statement::new();
+            open.push(Variable::Declaration(value.declared()));
 
             if !value.modifiers.is_empty() {
                 open.push(value.modifiers);
@@ -1,5 +1,5 @@
 use super::*;
-use codeviz_common::ElementFormatter;
+use codeviz_common::{ElementFormatter, Visitor, walk_element};
 use std::collections::{HashMap, BTreeSet};
 
 #[derive(Debug, Clone)]
@@ -23,6 +23,13 @@ impl FileSpec {
         self.elements.push(element);
     }
 
+    /// Types declared at the top level of this file.
+    pub fn types(&self) -> Vec<DeclaredType> {
+        let mut types = TopLevelTypes(Vec::new());
+        types.visit_elements(&self.elements);
+        types.0
+    }
+
     pub fn format<'a, W>(&'a self, out: &mut W) -> Result<()>
     where
         W: ::std::fmt::Write,
@@ -99,3 +106,23 @@ impl ToString for FileSpec {
         s
     }
 }
+
+/// Collects the declarations of top-level types.
+struct TopLevelTypes(Vec<DeclaredType>);
+
+impl Visitor<Variable> for TopLevelTypes {
+    fn visit_element(&mut self, element: &Element) {
+        // types in nested elements are members of other types.
+        if let Nested(_) = *element {
+            return;
+        }
+
+        walk_element(self, element);
+    }
+
+    fn visit_variable(&mut self, variable: &Variable) {
+        if let Variable::Declaration(ref declared) = *variable {
+            self.0.push(declared.clone());
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use codeviz_common::Walk;
 use super::*;
 
 pub trait ImportReceiver {
@@ -20,58 +21,50 @@ pub trait Imports {
         I: ImportReceiver;
 }
 
-impl Imports for Element {
+impl Imports for Statement {
     fn imports<I>(&self, receiver: &mut I)
     where
         I: ImportReceiver,
     {
-        match *self {
-            Inner(ref elements) => receiver.import_all(elements),
-            Push(ref statement) => statement.imports(receiver),
-            Concat(ref statement) => statement.imports(receiver),
-            Nested(ref nested) => {
-                (*nested).imports(receiver);
-            }
-            _ => {}
-        }
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
     }
 }
 
-impl Imports for Variable {
+impl Imports for Element {
     fn imports<I>(&self, receiver: &mut I)
     where
         I: ImportReceiver,
     {
-        match *self {
-            Variable::Type(ref ty) => {
-                ty.imports(receiver);
-            }
-            Variable::Statement(ref stmt) => {
-                stmt.imports(receiver);
-            }
-            Variable::Element(ref element) => {
-                element.imports(receiver);
-            }
-            _ => {}
-        }
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
     }
 }
 
-impl Imports for Statement {
+impl Imports for Elements {
     fn imports<I>(&self, receiver: &mut I)
     where
         I: ImportReceiver,
     {
-        receiver.import_all(&self.parts);
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
     }
 }
 
-impl Imports for Elements {
+impl Imports for Variable {
     fn imports<I>(&self, receiver: &mut I)
     where
         I: ImportReceiver,
     {
-        receiver.import_all(&self.elements);
+        match *self {
+            Variable::Type(ref ty) => {
+                ty.imports(receiver);
+            }
+            Variable::Statement(ref stmt) => {
+                stmt.imports(receiver);
+            }
+            Variable::Element(ref element) => {
+                element.imports(receiver);
+            }
+            _ => {}
+        }
     }
 }
 
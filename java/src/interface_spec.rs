@@ -37,6 +37,7 @@ impl From<InterfaceSpec> for Element {
         let mut elements = Elements::new();
 
         let mut open = Statement::new();
+        open.push(Variable::Declaration(value.declared()));
 
         for a in &value.annotations {
             elements.push(a);
@@ -0,0 +1,113 @@
+use super::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Default directory, relative to the project root, that sources are placed in.
+pub const DEFAULT_SOURCE_ROOT: &str = "src/main/java";
+
+/// A collection of files making up a tree of packages.
+#[derive(Debug, Clone)]
+pub struct JavaProject {
+    pub source_root: PathBuf,
+    pub files: Vec<FileSpec>,
+}
+
+impl JavaProject {
+    pub fn new() -> JavaProject {
+        JavaProject {
+            source_root: PathBuf::from(DEFAULT_SOURCE_ROOT),
+            files: Vec::new(),
+        }
+    }
+
+    pub fn source_root<P>(&mut self, source_root: P)
+    where
+        P: AsRef<Path>,
+    {
+        self.source_root = source_root.as_ref().to_owned();
+    }
+
+    pub fn push(&mut self, file: FileSpec) {
+        self.files.push(file);
+    }
+
+    /// Path of the given file, relative to the project root.
+    ///
+    /// The file must declare exactly one public top-level type, which gives the file its name.
+    pub fn file_path(&self, file: &FileSpec) -> Result<PathBuf> {
+        let public: Vec<DeclaredType> = file.types()
+            .into_iter()
+            .filter(|t| t.modifiers.contains(&Modifier::Public))
+            .collect();
+
+        let name = match public.len() {
+            0 => return Err(ErrorKind::NoPublicType(file.package.clone()).into()),
+            1 => &public[0].name,
+            _ => {
+                let names = public.iter().map(|t| t.name.clone()).collect();
+                return Err(
+                    ErrorKind::MultiplePublicTypes(file.package.clone(), names).into(),
+                );
+            }
+        };
+
+        let mut path = self.source_root.clone();
+
+        for part in file.package.split('.').filter(|p| !p.is_empty()) {
+            path.push(part);
+        }
+
+        path.push(format!("{}.java", name));
+        Ok(path)
+    }
+
+    /// Format all files into a map from their path to their content.
+    pub fn to_map(&self) -> Result<BTreeMap<PathBuf, String>> {
+        let mut out = BTreeMap::new();
+
+        for file in &self.files {
+            let path = self.file_path(file)?;
+
+            if out.contains_key(&path) {
+                return Err(ErrorKind::DuplicateFile(path.display().to_string()).into());
+            }
+
+            let mut content = String::new();
+            file.format(&mut content)?;
+            out.insert(path, content);
+        }
+
+        Ok(out)
+    }
+
+    /// Write all files to the given directory.
+    ///
+    /// All files are validated before anything is written.
+    pub fn write_to<P>(&self, root: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let root = root.as_ref();
+
+        for (path, content) in self.to_map()? {
+            let path = root.join(path);
+
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            let mut f = fs::File::create(&path)?;
+            f.write_all(content.as_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for JavaProject {
+    fn default() -> JavaProject {
+        JavaProject::new()
+    }
+}
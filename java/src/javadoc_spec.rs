@@ -0,0 +1,130 @@
+use super::*;
+
+/// Javadoc for methods and constructors.
+///
+/// `@param` and `@throws` tags are tied to the arguments and exceptions of the documented
+/// signature, which can be checked with `validate`.
+#[derive(Debug, Clone)]
+pub struct JavadocSpec {
+    pub description: Vec<Statement>,
+    pub params: Vec<(String, Statement)>,
+    pub returns: Option<Statement>,
+    pub throws: Vec<(ClassType, Statement)>,
+}
+
+impl JavadocSpec {
+    pub fn new() -> JavadocSpec {
+        JavadocSpec {
+            description: Vec::new(),
+            params: Vec::new(),
+            returns: None,
+            throws: Vec::new(),
+        }
+    }
+
+    /// Push a line to the description.
+    pub fn push<S>(&mut self, line: S)
+    where
+        S: Into<Statement>,
+    {
+        self.description.push(line.into());
+    }
+
+    pub fn param<S>(&mut self, argument: &ArgumentSpec, description: S)
+    where
+        S: Into<Statement>,
+    {
+        self.params.push((argument.name.clone(), description.into()));
+    }
+
+    pub fn returns<S>(&mut self, description: S)
+    where
+        S: Into<Statement>,
+    {
+        self.returns = Some(description.into());
+    }
+
+    pub fn throws<T, S>(&mut self, ty: T, description: S)
+    where
+        T: Into<ClassType>,
+        S: Into<Statement>,
+    {
+        self.throws.push((ty.into(), description.into()));
+    }
+
+    /// Build a `{@link Type}` reference, which will be imported like any other type.
+    pub fn link<T>(ty: T) -> Statement
+    where
+        T: Into<Type>,
+    {
+        let mut s = Statement::new();
+        s.push("{@link ");
+        s.push(ty.into());
+        s.push("}");
+        s
+    }
+
+    /// Check that all documented parameters and exceptions are part of the given signature.
+    pub fn validate(&self, arguments: &[ArgumentSpec], throws: &[ClassType]) -> Result<()> {
+        for (name, _) in &self.params {
+            if !arguments.iter().any(|a| a.name == *name) {
+                return Err(ErrorKind::UnknownParameter(name.clone()).into());
+            }
+        }
+
+        for (ty, _) in &self.throws {
+            if !throws.iter().any(|t| t.to_raw() == ty.to_raw()) {
+                let name = format!("{}.{}", ty.package, ty.name);
+                return Err(ErrorKind::UnknownThrows(name).into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for JavadocSpec {
+    fn default() -> JavadocSpec {
+        JavadocSpec::new()
+    }
+}
+
+impl From<JavadocSpec> for Element {
+    fn from(value: JavadocSpec) -> Element {
+        let mut lines = value.description;
+
+        let mut tags = Vec::new();
+
+        for (name, description) in value.params {
+            let mut s = Statement::new();
+            s.push("@param ");
+            s.push(name);
+            s.push(" ");
+            s.push(description);
+            tags.push(s);
+        }
+
+        if let Some(returns) = value.returns {
+            let mut s = Statement::new();
+            s.push("@return ");
+            s.push(returns);
+            tags.push(s);
+        }
+
+        for (ty, description) in value.throws {
+            let mut s = Statement::new();
+            s.push("@throws ");
+            s.push(ty);
+            s.push(" ");
+            s.push(description);
+            tags.push(s);
+        }
+
+        if !lines.is_empty() && !tags.is_empty() {
+            lines.push(Statement::new());
+        }
+
+        lines.extend(tags);
+        javadoc(lines).into()
+    }
+}
@@ -9,16 +9,22 @@ mod class_spec;
 mod common;
 mod constructor_spec;
 mod container_spec;
+mod enum_constant_spec;
 mod enum_spec;
 mod extra;
 mod field_spec;
 mod file_spec;
 mod imports;
 mod interface_spec;
+mod java_project;
+mod javadoc_spec;
 mod method_argument;
 mod method_spec;
 mod modifier;
+mod type_declaration;
+mod value_class_builder;
 mod variable;
+mod visitor;
 
 pub use codeviz_common::Element::*;
 pub use self::_type::*;
@@ -28,15 +34,21 @@ pub use self::class_like::*;
 pub use self::class_spec::*;
 pub use self::constructor_spec::*;
 pub use self::container_spec::*;
+pub use self::enum_constant_spec::*;
 pub use self::enum_spec::*;
 pub use self::field_spec::*;
 pub use self::file_spec::*;
 pub use self::imports::*;
 pub use self::interface_spec::*;
+pub use self::java_project::*;
+pub use self::javadoc_spec::*;
 pub use self::method_argument::*;
 pub use self::method_spec::*;
 pub use self::modifier::*;
+pub use self::type_declaration::*;
+pub use self::value_class_builder::*;
 pub use self::variable::*;
+pub use self::visitor::*;
 pub(crate) use self::common::*;
 pub(crate) use self::errors::*;
 pub(crate) use self::extra::*;
@@ -4,6 +4,7 @@ use super::*;
 pub struct MethodSpec {
     pub modifiers: Modifiers,
     pub name: String,
+    pub javadoc: Option<JavadocSpec>,
     pub annotations: Vec<AnnotationSpec>,
     pub arguments: Vec<ArgumentSpec>,
     pub returns: Option<Type>,
@@ -16,6 +17,7 @@ impl MethodSpec {
         MethodSpec {
             modifiers: modifiers,
             name: name.to_owned(),
+            javadoc: None,
             annotations: Vec::new(),
             arguments: Vec::new(),
             returns: None,
@@ -24,6 +26,19 @@ impl MethodSpec {
         }
     }
 
+    pub fn javadoc(&mut self, javadoc: JavadocSpec) {
+        self.javadoc = Some(javadoc);
+    }
+
+    /// Check that the javadoc, if any, only documents arguments and exceptions of this method.
+    pub fn validate_javadoc(&self) -> Result<()> {
+        if let Some(ref javadoc) = self.javadoc {
+            javadoc.validate(&self.arguments, &self.throws)?;
+        }
+
+        Ok(())
+    }
+
     pub fn push_annotation<A>(&mut self, annotation: A)
     where
         A: Into<AnnotationSpec>,
@@ -64,6 +79,10 @@ impl From<MethodSpec> for Element {
     fn from(value: MethodSpec) -> Element {
         let mut elements = Elements::new();
 
+        if let Some(javadoc) = value.javadoc {
+            elements.push(javadoc);
+        }
+
         for a in &value.annotations {
             elements.push(a);
         }
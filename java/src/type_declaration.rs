@@ -0,0 +1,65 @@
+use super::*;
+
+/// A type declared by a spec.
+#[derive(Debug, Clone)]
+pub struct DeclaredType {
+    pub modifiers: Modifiers,
+    pub name: String,
+}
+
+/// Specs which declare a named type, like classes, interfaces, and enums.
+pub trait TypeDeclaration {
+    fn name(&self) -> &str;
+
+    fn modifiers(&self) -> &Modifiers;
+
+    fn declared(&self) -> DeclaredType {
+        DeclaredType {
+            modifiers: self.modifiers().clone(),
+            name: self.name().to_owned(),
+        }
+    }
+}
+
+impl<T> TypeDeclaration for &T
+where
+    T: TypeDeclaration,
+{
+    fn name(&self) -> &str {
+        (*self).name()
+    }
+
+    fn modifiers(&self) -> &Modifiers {
+        (*self).modifiers()
+    }
+}
+
+impl TypeDeclaration for ClassSpec {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn modifiers(&self) -> &Modifiers {
+        &self.modifiers
+    }
+}
+
+impl TypeDeclaration for InterfaceSpec {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn modifiers(&self) -> &Modifiers {
+        &self.modifiers
+    }
+}
+
+impl TypeDeclaration for EnumSpec {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn modifiers(&self) -> &Modifiers {
+        &self.modifiers
+    }
+}
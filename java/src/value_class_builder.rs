@@ -0,0 +1,361 @@
+use super::*;
+
+/// Builds an immutable value class out of a set of fields.
+///
+/// The generated class has private final fields, a constructor taking all fields, getters,
+/// `equals`, `hashCode`, `toString`, and a nested `Builder`.
+#[derive(Debug, Clone)]
+pub struct ValueClassBuilder {
+    pub modifiers: Modifiers,
+    pub name: String,
+    pub fields: Vec<FieldSpec>,
+    pub jackson: bool,
+    pub null_checks: bool,
+}
+
+impl ValueClassBuilder {
+    pub fn new(modifiers: Modifiers, name: &str) -> ValueClassBuilder {
+        ValueClassBuilder {
+            modifiers: modifiers,
+            name: name.to_owned(),
+            fields: Vec::new(),
+            jackson: false,
+            null_checks: false,
+        }
+    }
+
+    pub fn push_field<F>(&mut self, field: F)
+    where
+        F: Into<FieldSpec>,
+    {
+        self.fields.push(field.into());
+    }
+
+    /// Add Jackson annotations to the constructor and getters.
+    pub fn jackson(&mut self) {
+        self.jackson = true;
+    }
+
+    /// Check that non-primitive arguments to the constructor are not null.
+    pub fn null_checks(&mut self) {
+        self.null_checks = true;
+    }
+
+    pub fn build(self) -> ClassSpec {
+        let objects = Type::class("java.util", "Objects");
+        let override_type = Type::class("java.lang", "Override");
+        let json_property = Type::class("com.fasterxml.jackson.annotation", "JsonProperty");
+        let json_creator = Type::class("com.fasterxml.jackson.annotation", "JsonCreator");
+
+        let mut class = ClassSpec::new(self.modifiers.clone(), &self.name);
+
+        for field in &self.fields {
+            class.push_field(FieldSpec::new(
+                modifiers(vec![Modifier::Private, Modifier::Final]),
+                &field.ty,
+                &field.name,
+            ));
+        }
+
+        // constructor
+        {
+            let mut constructor = ConstructorSpec::new(modifiers(vec![Modifier::Public]));
+
+            if self.jackson && !self.fields.is_empty() {
+                constructor.push_annotation(&json_creator);
+            }
+
+            for field in &self.fields {
+                let mut argument =
+                    ArgumentSpec::new(modifiers(vec![Modifier::Final]), &field.ty, &field.name);
+
+                if self.jackson {
+                    argument.push_annotation(&property_annotation(&json_property, &field.name));
+                }
+
+                constructor.push_argument(argument);
+
+                let mut assign = Statement::new();
+                assign.push("this.");
+                assign.push(&field.name);
+                assign.push(" = ");
+
+                if self.null_checks && !is_primitive(&field.ty) {
+                    assign.push(&objects);
+                    assign.push(".requireNonNull(");
+                    assign.push(&field.name);
+                    assign.push(", ");
+                    assign.push(Variable::String(field.name.clone()));
+                    assign.push(")");
+                } else {
+                    assign.push(&field.name);
+                }
+
+                assign.push(";");
+                constructor.push(assign);
+            }
+
+            class.push_constructor(constructor);
+        }
+
+        // getters
+        for field in &self.fields {
+            let prefix = match field.ty {
+                Type::Primitive(ref p) if *p == BOOLEAN => "is",
+                _ => "get",
+            };
+
+            let mut getter = MethodSpec::new(
+                modifiers(vec![Modifier::Public]),
+                &format!("{}{}", prefix, upper_first(&field.name)),
+            );
+
+            if self.jackson {
+                getter.push_annotation(property_annotation(&json_property, &field.name));
+            }
+
+            getter.returns(&field.ty);
+
+            let mut body = Statement::new();
+            body.push("return this.");
+            body.push(&field.name);
+            body.push(";");
+            getter.push(body);
+
+            class.push(getter);
+        }
+
+        class.push(self.equals(&objects, &override_type));
+        class.push(self.hash_code(&objects, &override_type));
+        class.push(self.to_string_method(&override_type));
+
+        // builder
+        {
+            let builder_type = Type::local("Builder");
+
+            let mut method = MethodSpec::new(
+                modifiers(vec![Modifier::Public, Modifier::Static]),
+                "builder",
+            );
+            method.returns(&builder_type);
+            method.push("return new Builder();");
+            class.push(method);
+
+            class.push(self.builder(&builder_type));
+        }
+
+        class
+    }
+
+    fn equals(&self, objects: &ClassType, override_type: &ClassType) -> MethodSpec {
+        let object = Type::class("java.lang", "Object");
+
+        let mut equals = MethodSpec::new(modifiers(vec![Modifier::Public]), "equals");
+        equals.push_annotation(override_type);
+        equals.returns(BOOLEAN);
+        equals.push_argument(ArgumentSpec::new(
+            modifiers(vec![Modifier::Final]),
+            &object,
+            "other",
+        ));
+
+        {
+            let mut same = Elements::new();
+            same.push("if (this == other) {");
+            same.push_nested("return true;");
+            same.push("}");
+            equals.push(same);
+        }
+
+        {
+            let mut instance = Elements::new();
+            instance.push(format!("if (!(other instanceof {})) {{", self.name).as_str());
+            instance.push_nested("return false;");
+            instance.push("}");
+            equals.push(instance);
+        }
+
+        if self.fields.is_empty() {
+            equals.push("return true;");
+            return equals;
+        }
+
+        let mut body = Elements::new();
+        body.push(format!("final {} o = ({}) other;", self.name, self.name).as_str());
+
+        let mut comparisons = Vec::new();
+
+        for field in &self.fields {
+            let mut c = Statement::new();
+
+            match field.ty {
+                Type::Primitive(ref p) if *p == FLOAT || *p == DOUBLE => {
+                    c.push(p.boxed);
+                    c.push(format!(
+                        ".compare(this.{}, o.{}) == 0",
+                        field.name, field.name
+                    ));
+                }
+                Type::Primitive(_) => {
+                    c.push(format!("this.{} == o.{}", field.name, field.name));
+                }
+                _ => {
+                    c.push(objects);
+                    c.push(format!(".equals(this.{}, o.{})", field.name, field.name));
+                }
+            }
+
+            comparisons.push(c);
+        }
+
+        let mut compare = Statement::new();
+        compare.push("return ");
+        compare.push(join_statements(comparisons, " && "));
+        compare.push(";");
+
+        body.push(compare);
+        equals.push(body);
+        equals
+    }
+
+    fn hash_code(&self, objects: &ClassType, override_type: &ClassType) -> MethodSpec {
+        let mut hash_code = MethodSpec::new(modifiers(vec![Modifier::Public]), "hashCode");
+        hash_code.push_annotation(override_type);
+        hash_code.returns(INTEGER);
+
+        let arguments = self.fields.iter().map(|f| format!("this.{}", f.name));
+
+        let mut body = Statement::new();
+        body.push("return ");
+        body.push(objects);
+        body.push(".hash(");
+        body.push(join_statements(arguments, ", "));
+        body.push(");");
+
+        hash_code.push(body);
+        hash_code
+    }
+
+    fn to_string_method(&self, override_type: &ClassType) -> MethodSpec {
+        let string = Type::class("java.lang", "String");
+
+        let mut to_string = MethodSpec::new(modifiers(vec![Modifier::Public]), "toString");
+        to_string.push_annotation(override_type);
+        to_string.returns(&string);
+
+        let mut body = Statement::new();
+        body.push("return ");
+
+        let mut literal = format!("{}(", self.name);
+
+        for (i, field) in self.fields.iter().enumerate() {
+            if i > 0 {
+                literal.push_str(", ");
+            }
+
+            literal.push_str(&field.name);
+            literal.push('=');
+
+            body.push(Variable::String(literal));
+            body.push(format!(" + this.{} + ", field.name));
+            literal = String::new();
+        }
+
+        literal.push(')');
+        body.push(Variable::String(literal));
+        body.push(";");
+
+        to_string.push(body);
+        to_string
+    }
+
+    fn builder(&self, builder_type: &Local) -> ClassSpec {
+        let mut builder = ClassSpec::new(
+            modifiers(vec![Modifier::Public, Modifier::Static]),
+            "Builder",
+        );
+
+        for field in &self.fields {
+            builder.push_field(FieldSpec::new(
+                modifiers(vec![Modifier::Private]),
+                &field.ty,
+                &field.name,
+            ));
+        }
+
+        for field in &self.fields {
+            let mut setter = MethodSpec::new(modifiers(vec![Modifier::Public]), &field.name);
+            setter.returns(builder_type);
+            setter.push_argument(ArgumentSpec::new(
+                modifiers(vec![Modifier::Final]),
+                &field.ty,
+                &field.name,
+            ));
+
+            let mut body = Elements::new();
+            body.push(format!("this.{} = {};", field.name, field.name).as_str());
+            body.push("return this;");
+            setter.push(body);
+
+            builder.push(setter);
+        }
+
+        let mut build = MethodSpec::new(modifiers(vec![Modifier::Public]), "build");
+        build.returns(Type::local(&self.name));
+
+        let arguments = self.fields.iter().map(|f| format!("this.{}", f.name));
+
+        let mut body = Statement::new();
+        body.push(format!("return new {}(", self.name));
+        body.push(join_statements(arguments, ", "));
+        body.push(");");
+        build.push(body);
+
+        builder.push(build);
+        builder
+    }
+}
+
+impl From<ValueClassBuilder> for ClassSpec {
+    fn from(value: ValueClassBuilder) -> ClassSpec {
+        value.build()
+    }
+}
+
+impl From<ValueClassBuilder> for Element {
+    fn from(value: ValueClassBuilder) -> Element {
+        value.build().into()
+    }
+}
+
+fn property_annotation(json_property: &ClassType, name: &str) -> AnnotationSpec {
+    let mut annotation = AnnotationSpec::new(json_property);
+    annotation.push_argument(Variable::String(name.to_owned()));
+    annotation
+}
+
+fn modifiers(modifiers: Vec<Modifier>) -> Modifiers {
+    let mut out = Modifiers::new();
+
+    for m in modifiers {
+        out.insert(m);
+    }
+
+    out
+}
+
+fn is_primitive(ty: &Type) -> bool {
+    match *ty {
+        Type::Primitive(_) => true,
+        _ => false,
+    }
+}
+
+fn upper_first(name: &str) -> String {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
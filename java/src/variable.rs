@@ -10,6 +10,8 @@ pub enum Variable {
     Statement(Statement),
     Element(Element),
     Spacing,
+    /// Marks the statement as the declaration of a type, without being formatted.
+    Declaration(DeclaredType),
 }
 
 impl VariableFormat for Variable {
@@ -26,6 +28,7 @@ impl VariableFormat for Variable {
             Variable::Literal(ref content) => out.write_str(content)?,
             Variable::Element(ref element) => element.format(out, extra)?,
             Variable::Spacing => out.new_line()?,
+            Variable::Declaration(_) => {}
         };
 
         Ok(())
@@ -0,0 +1,34 @@
+use codeviz_common::{Visitor, VisitorMut};
+use super::*;
+
+/// Walk the children of a variable, like nested statements and elements.
+pub fn walk_variable<V>(visitor: &mut V, variable: &Variable)
+where
+    V: Visitor<Variable> + ?Sized,
+{
+    match *variable {
+        Variable::Statement(ref stmt) => visitor.visit_statement(stmt),
+        Variable::Element(ref element) => visitor.visit_element(element),
+        Variable::Literal(_) |
+        Variable::Type(_) |
+        Variable::String(_) |
+        Variable::Spacing |
+        Variable::Declaration(_) => {}
+    }
+}
+
+/// Walk the children of a variable, permitting them to be rewritten.
+pub fn walk_variable_mut<V>(visitor: &mut V, variable: &mut Variable)
+where
+    V: VisitorMut<Variable> + ?Sized,
+{
+    match *variable {
+        Variable::Statement(ref mut stmt) => visitor.visit_statement_mut(stmt),
+        Variable::Element(ref mut element) => visitor.visit_element_mut(element),
+        Variable::Literal(_) |
+        Variable::Type(_) |
+        Variable::String(_) |
+        Variable::Spacing |
+        Variable::Declaration(_) => {}
+    }
+}
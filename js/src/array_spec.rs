@@ -0,0 +1,86 @@
+use super::*;
+use codeviz_common::ElementFormat;
+
+/// A single entry in an array literal.
+#[derive(Debug, Clone)]
+pub enum ArrayEntry {
+    Value(Statement),
+    /// A spread, like `...other`.
+    Spread(Statement),
+}
+
+/// An array literal, like `[1, 2, ...rest]`.
+#[derive(Debug, Clone)]
+pub struct ArraySpec {
+    pub entries: Vec<ArrayEntry>,
+    pub width: usize,
+}
+
+impl ArraySpec {
+    pub fn new() -> ArraySpec {
+        ArraySpec {
+            entries: Vec::new(),
+            width: DEFAULT_WIDTH,
+        }
+    }
+
+    /// Set the width above which entries are put on separate lines.
+    pub fn width(&mut self, width: usize) {
+        self.width = width;
+    }
+
+    pub fn push<S>(&mut self, value: S)
+    where
+        S: Into<Statement>,
+    {
+        self.entries.push(ArrayEntry::Value(value.into()));
+    }
+
+    pub fn push_spread<S>(&mut self, value: S)
+    where
+        S: Into<Statement>,
+    {
+        self.entries.push(ArrayEntry::Spread(value.into()));
+    }
+
+    pub fn format<E>(&self, out: &mut E, depth: usize, extra: &mut Extra) -> Result<()>
+    where
+        E: ElementFormat,
+    {
+        let entries: Vec<Statement> = self.entries
+            .iter()
+            .map(|entry| match *entry {
+                ArrayEntry::Value(ref value) => value.clone(),
+                ArrayEntry::Spread(ref value) => {
+                    let mut out = Statement::new();
+                    out.push("...");
+                    out.push(value.clone());
+                    out
+                }
+            })
+            .collect();
+
+        format_entries(out, depth, extra, ('[', ']'), &entries, self.width)
+    }
+}
+
+impl<'a, T> From<&'a T> for ArraySpec
+where
+    T: Into<ArraySpec> + Clone,
+{
+    fn from(value: &'a T) -> ArraySpec {
+        value.clone().into()
+    }
+}
+
+impl From<ArraySpec> for Variable {
+    fn from(value: ArraySpec) -> Variable {
+        Variable::Array(value)
+    }
+}
+
+impl From<ArraySpec> for Statement {
+    fn from(value: ArraySpec) -> Statement {
+        Variable::Array(value).into()
+    }
+}
@@ -0,0 +1,121 @@
+use super::*;
+use codeviz_common::ElementFormat;
+
+/// The body of an arrow function.
+#[derive(Debug, Clone)]
+pub enum ArrowBody {
+    /// An expression which is returned, like `(a) => a + 1`.
+    Expression(Statement),
+    /// A block of statements, like `(a) => { ... }`.
+    Block(Elements),
+}
+
+/// An arrow function expression.
+#[derive(Debug, Clone)]
+pub struct ArrowFunctionSpec {
+    pub arguments: Vec<Statement>,
+    pub body: ArrowBody,
+    pub is_async: bool,
+}
+
+impl ArrowFunctionSpec {
+    /// An arrow function returning the given expression.
+    pub fn expression<S>(body: S) -> ArrowFunctionSpec
+    where
+        S: Into<Statement>,
+    {
+        ArrowFunctionSpec {
+            arguments: Vec::new(),
+            body: ArrowBody::Expression(body.into()),
+            is_async: false,
+        }
+    }
+
+    /// An arrow function with a block body, see `push`.
+    pub fn block() -> ArrowFunctionSpec {
+        ArrowFunctionSpec {
+            arguments: Vec::new(),
+            body: ArrowBody::Block(Elements::new()),
+            is_async: false,
+        }
+    }
+
+    /// Make the function `async`.
+    pub fn asynchronous(&mut self) {
+        self.is_async = true;
+    }
+
+    pub fn push_argument<S>(&mut self, argument: S)
+    where
+        S: Into<Statement>,
+    {
+        self.arguments.push(argument.into());
+    }
+
+    /// Push an element to the body, turning an expression body into a block.
+    pub fn push<E>(&mut self, element: E)
+    where
+        E: Into<Element>,
+    {
+        if let ArrowBody::Expression(_) = self.body {
+            self.body = ArrowBody::Block(Elements::new());
+        }
+
+        if let ArrowBody::Block(ref mut elements) = self.body {
+            elements.push(element);
+        }
+    }
+
+    pub fn format<E>(&self, out: &mut E, depth: usize, extra: &mut Extra) -> Result<()>
+    where
+        E: ElementFormat,
+    {
+        if self.is_async {
+            out.write_str("async ")?;
+        }
+
+        out.write_char('(')?;
+        Statement::from(self.arguments.clone())
+            .join(", ")
+            .format(out, depth, extra)?;
+        out.write_str(") => ")?;
+
+        match self.body {
+            ArrowBody::Expression(ref stmt) => {
+                // object literals would be parsed as a block.
+                let object = match stmt.parts.first() {
+                    Some(&Variable::Object(_)) => stmt.parts.len() == 1,
+                    _ => false,
+                };
+
+                if object {
+                    out.write_char('(')?;
+                    stmt.format(out, depth, extra)?;
+                    out.write_char(')')?;
+                } else {
+                    stmt.format(out, depth, extra)?;
+                }
+            }
+            ArrowBody::Block(ref elements) => {
+                out.write_char('{')?;
+                Nested(Box::new(elements.clone().join(Spacing).into())).format(out, extra)?;
+                out.new_line_unless_empty()?;
+                out.write_char('}')?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<ArrowFunctionSpec> for Variable {
+    fn from(value: ArrowFunctionSpec) -> Variable {
+        Variable::Arrow(value)
+    }
+}
+
+impl From<ArrowFunctionSpec> for Statement {
+    fn from(value: ArrowFunctionSpec) -> Statement {
+        Variable::Arrow(value).into()
+    }
+}
@@ -3,6 +3,8 @@ use super::*;
 #[derive(Debug, Clone)]
 pub struct ClassSpec {
     pub name: String,
+    pub extends: Option<Name>,
+    pub fields: Vec<FieldSpec>,
     pub constructors: Elements,
     pub elements: Elements,
     pub export: bool,
@@ -12,6 +14,8 @@ impl ClassSpec {
     pub fn new(name: &str) -> ClassSpec {
         ClassSpec {
             name: name.to_owned(),
+            extends: None,
+            fields: Vec::new(),
             constructors: Elements::new(),
             elements: Elements::new(),
             export: false,
@@ -22,6 +26,21 @@ impl ClassSpec {
         self.export = true;
     }
 
+    /// Set the class being extended, an imported name is imported by the file.
+    pub fn extends<N>(&mut self, extends: N)
+    where
+        N: Into<Name>,
+    {
+        self.extends = Some(extends.into());
+    }
+
+    pub fn push_field<F>(&mut self, field: F)
+    where
+        F: Into<FieldSpec>,
+    {
+        self.fields.push(field.into());
+    }
+
     pub fn push_constructor<E>(&mut self, element: E)
     where
         E: Into<Element>,
@@ -42,15 +61,31 @@ impl From<ClassSpec> for Element {
         let mut open = Statement::new();
 
         if value.export {
-            open.push("export ");
+            open.push(Variable::Export(value.name.clone()));
         }
 
         open.push("class ");
         open.push(value.name);
+
+        if let Some(extends) = value.extends {
+            open.push(" extends ");
+            open.push(extends);
+        }
+
         open.push(" {");
 
         let mut body = Elements::new();
 
+        if !value.fields.is_empty() {
+            let mut fields = Elements::new();
+
+            for field in value.fields {
+                fields.push(field);
+            }
+
+            body.push(fields);
+        }
+
         if !value.constructors.is_empty() {
             body.push(value.constructors.join(Spacing));
         }
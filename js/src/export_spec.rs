@@ -0,0 +1,94 @@
+/// An export from a module.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ExportSpec {
+    /// `export {name};` or `export {name as alias};`
+    Named { name: String, alias: Option<String> },
+    /// `export default name;`
+    Default { name: String },
+    /// `export {name} from "module";` or `export {name as alias} from "module";`
+    ReExport {
+        module: String,
+        name: String,
+        alias: Option<String>,
+    },
+    /// `export * from "module";` or `export * as alias from "module";`
+    ReExportAll {
+        module: String,
+        alias: Option<String>,
+    },
+}
+
+impl ExportSpec {
+    pub fn named(name: &str) -> ExportSpec {
+        ExportSpec::Named {
+            name: name.to_owned(),
+            alias: None,
+        }
+    }
+
+    pub fn named_alias(name: &str, alias: &str) -> ExportSpec {
+        ExportSpec::Named {
+            name: name.to_owned(),
+            alias: Some(alias.to_owned()),
+        }
+    }
+
+    pub fn default_export(name: &str) -> ExportSpec {
+        ExportSpec::Default { name: name.to_owned() }
+    }
+
+    pub fn re_export(module: &str, name: &str) -> ExportSpec {
+        ExportSpec::ReExport {
+            module: module.to_owned(),
+            name: name.to_owned(),
+            alias: None,
+        }
+    }
+
+    pub fn re_export_alias(module: &str, name: &str, alias: &str) -> ExportSpec {
+        ExportSpec::ReExport {
+            module: module.to_owned(),
+            name: name.to_owned(),
+            alias: Some(alias.to_owned()),
+        }
+    }
+
+    pub fn re_export_all(module: &str) -> ExportSpec {
+        ExportSpec::ReExportAll {
+            module: module.to_owned(),
+            alias: None,
+        }
+    }
+
+    pub fn re_export_all_alias(module: &str, alias: &str) -> ExportSpec {
+        ExportSpec::ReExportAll {
+            module: module.to_owned(),
+            alias: Some(alias.to_owned()),
+        }
+    }
+
+    /// The name that this export is visible as to importers, if any.
+    pub fn exported_name(&self) -> Option<&str> {
+        match *self {
+            ExportSpec::Named {
+                ref name,
+                ref alias,
+            } |
+            ExportSpec::ReExport {
+                ref name,
+                ref alias,
+                ..
+            } => Some(alias.as_ref().map(String::as_str).unwrap_or(name)),
+            ExportSpec::Default { .. } => Some("default"),
+            ExportSpec::ReExportAll { ref alias, .. } => alias.as_ref().map(String::as_str),
+        }
+    }
+}
+
+/// Format the name of an export, including its alias if it has one.
+pub(crate) fn export_name(name: &str, alias: Option<&str>) -> String {
+    match alias {
+        Some(alias) => format!("{} as {}", name, alias),
+        None => name.to_owned(),
+    }
+}
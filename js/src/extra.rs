@@ -0,0 +1,35 @@
+/// Module system that a file is generated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleSystem {
+    /// ES modules, using `import` and `export`.
+    Esm,
+    /// CommonJS, using `require` and `module.exports`.
+    CommonJs,
+}
+
+impl Default for ModuleSystem {
+    fn default() -> ModuleSystem {
+        ModuleSystem::Esm
+    }
+}
+
+pub struct Extra {
+    pub module_system: ModuleSystem,
+    /// If strings should be escaped to only contain printable ASCII.
+    pub ascii_only: bool,
+}
+
+impl Extra {
+    pub fn new(module_system: ModuleSystem) -> Extra {
+        Extra {
+            module_system: module_system,
+            ascii_only: false,
+        }
+    }
+}
+
+impl Default for Extra {
+    fn default() -> Extra {
+        Extra::new(ModuleSystem::default())
+    }
+}
@@ -0,0 +1,79 @@
+use super::*;
+
+/// A field declared in the body of a class, like `static #count = 0;`.
+#[derive(Debug, Clone)]
+pub struct FieldSpec {
+    pub name: String,
+    pub initializer: Option<Statement>,
+    pub is_static: bool,
+    pub is_private: bool,
+}
+
+impl FieldSpec {
+    pub fn new(name: &str) -> FieldSpec {
+        FieldSpec {
+            name: name.to_owned(),
+            initializer: None,
+            is_static: false,
+            is_private: false,
+        }
+    }
+
+    pub fn with_static(name: &str) -> FieldSpec {
+        FieldSpec {
+            is_static: true,
+            ..FieldSpec::new(name)
+        }
+    }
+
+    /// Make the field private, which prefixes its name with `#`.
+    pub fn private(&mut self) {
+        self.is_private = true;
+    }
+
+    pub fn initializer<S>(&mut self, initializer: S)
+    where
+        S: Into<Statement>,
+    {
+        self.initializer = Some(initializer.into());
+    }
+}
+
+impl<'a, T> From<&'a T> for FieldSpec
+where
+    T: Into<FieldSpec> + Clone,
+{
+    fn from(value: &'a T) -> FieldSpec {
+        value.clone().into()
+    }
+}
+
+impl From<FieldSpec> for Statement {
+    fn from(value: FieldSpec) -> Statement {
+        let mut out = Statement::new();
+
+        if value.is_static {
+            out.push("static ");
+        }
+
+        if value.is_private {
+            out.push("#");
+        }
+
+        out.push(value.name);
+
+        if let Some(initializer) = value.initializer {
+            out.push(" = ");
+            out.push(initializer);
+        }
+
+        out.push(";");
+        out
+    }
+}
+
+impl From<FieldSpec> for Element {
+    fn from(value: FieldSpec) -> Element {
+        Push(value.into())
+    }
+}
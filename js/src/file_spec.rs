@@ -1,17 +1,55 @@
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
-use std::collections::btree_map;
+use std::rc::Rc;
 use super::*;
-use codeviz_common::ElementFormatter;
+use codeviz_common::{ElementFormatter, Walk};
 
 #[derive(Debug, Clone)]
 pub struct FileSpec {
     pub elements: Elements,
+    pub imports: Vec<ImportedName>,
+    pub exports: Vec<ExportSpec>,
+    pub resolver: Rc<dyn ModuleResolver>,
+    pub module_system: ModuleSystem,
+    pub ascii_only: bool,
 }
 
 impl FileSpec {
     pub fn new() -> FileSpec {
-        FileSpec { elements: Elements::new() }
+        FileSpec {
+            elements: Elements::new(),
+            imports: Vec::new(),
+            exports: Vec::new(),
+            resolver: Rc::new(PathResolver::default()),
+            module_system: ModuleSystem::default(),
+            ascii_only: false,
+        }
+    }
+
+    pub fn module_system(&mut self, module_system: ModuleSystem) {
+        self.module_system = module_system;
+    }
+
+    /// Escape all characters in strings which are not printable ASCII.
+    pub fn ascii_only(&mut self) {
+        self.ascii_only = true;
+    }
+
+    /// Set the resolver used to turn module names into import specifiers.
+    pub fn resolver<R>(&mut self, resolver: R)
+    where
+        R: ModuleResolver + 'static,
+    {
+        self.resolver = Rc::new(resolver);
+    }
+
+    /// Import a name, even if it isn't used by any element.
+    pub fn push_import(&mut self, import: ImportedName) {
+        self.imports.push(import);
+    }
+
+    pub fn push_export(&mut self, export: ExportSpec) {
+        self.exports.push(export);
     }
 
     pub fn push<E>(&mut self, element: E)
@@ -21,68 +59,257 @@ impl FileSpec {
         self.elements.push(element);
     }
 
-    fn module_to_path(&self, path: &str) -> String {
-        let parts: Vec<&str> = path.split(".").collect();
-        format!("{}.js", parts.join("/"))
+    fn module_to_path(&self, module: &str) -> String {
+        self.resolver.resolve(module)
     }
 
-    fn imports(&self) -> Option<Elements> {
-        let mut imports = BTreeSet::new();
+    fn imports(&self) -> Result<Option<Elements>> {
+        // imports in the order they were pushed, then in the order they are used.
+        let mut imports = self.imports.clone();
         self.elements.imports(&mut imports);
 
-        // specific imports from modules.
-        let mut collected: BTreeMap<String, Vec<String>> = BTreeMap::new();
-        // * imported modules, and their alias.
-        let mut wildcard: BTreeSet<(String, String)> = BTreeSet::new();
-
-        for import in imports {
-            if let Some(alias) = import.alias {
-                wildcard.insert((import.module, alias));
-            } else {
-                match collected.entry(import.module.clone()) {
-                    btree_map::Entry::Vacant(entry) => {
-                        entry.insert(vec![import.name.clone()]);
+        let modules = ModuleImports::group(&imports)?;
+
+        if modules.is_empty() {
+            return Ok(None);
+        }
+
+        let mut out = Elements::new();
+
+        for module in ModuleImports::side_effects(&imports) {
+            // importing any bindings runs the side effects as well.
+            if modules[&module].has_bindings() {
+                continue;
+            }
+
+            let path = self.module_to_path(&module);
+            let mut stmt = Statement::new();
+
+            match self.module_system {
+                ModuleSystem::Esm => {
+                    stmt.push("import ");
+                    stmt.push(Variable::String(path));
+                    stmt.push(";");
+                }
+                ModuleSystem::CommonJs => {
+                    stmt.push("require(");
+                    stmt.push(Variable::String(path));
+                    stmt.push(");");
+                }
+            }
+
+            out.push(stmt);
+        }
+
+        for (module, imports) in modules {
+            let path = self.module_to_path(&module);
+
+            match self.module_system {
+                ModuleSystem::Esm => {
+                    for clause in imports.clauses() {
+                        let mut stmt = Statement::new();
+                        stmt.push("import ");
+                        stmt.push(clause);
+                        stmt.push(" from ");
+                        stmt.push(Variable::String(path.clone()));
+                        stmt.push(";");
+                        out.push(stmt);
                     }
-                    btree_map::Entry::Occupied(entry) => {
-                        entry.into_mut().push(import.name.clone());
+                }
+                ModuleSystem::CommonJs => {
+                    for binding in imports.bindings() {
+                        let mut stmt = Statement::new();
+                        stmt.push("const ");
+                        stmt.push(binding);
+                        stmt.push(" = require(");
+                        stmt.push(Variable::String(path.clone()));
+                        stmt.push(");");
+                        out.push(stmt);
                     }
                 }
             }
         }
 
-        if collected.is_empty() && wildcard.is_empty() {
-            return None;
+        Ok(Some(out))
+    }
+
+    /// Names of declarations which are exported where they are declared.
+    fn declared(&self) -> Vec<String> {
+        let mut declared = Vec::new();
+
+        self.elements.walk(&mut |variable: &Variable| {
+            if let Variable::Export(ref name) = *variable {
+                declared.push(name.clone());
+            }
+        });
+
+        declared
+    }
+
+    /// Build the exports of this file.
+    ///
+    /// `declared` are the names of declarations which are exported where they are declared,
+    /// which only need separate exports for CommonJS.
+    fn exports(&self, declared: &[String]) -> Result<Option<Elements>> {
+        let exports: BTreeSet<&ExportSpec> = self.exports.iter().collect();
+
+        let mut exported: BTreeSet<&str> = declared.iter().map(String::as_str).collect();
+
+        for export in &exports {
+            if let Some(name) = export.exported_name() {
+                if !exported.insert(name) {
+                    return Err(ErrorKind::DuplicateExport(name.to_owned()).into());
+                }
+            }
+        }
+
+        // local names exported, and their optional alias.
+        let mut named: Vec<(&str, Option<&str>)> = Vec::new();
+        // local name exported as default.
+        let mut default: Option<&str> = None;
+        // specific names re-exported from modules, and their optional alias.
+        let mut re_exported: BTreeMap<&str, Vec<(&str, Option<&str>)>> = BTreeMap::new();
+        // modules re-exported in their entirety, and their optional alias.
+        let mut re_exported_all: Vec<(&str, Option<&str>)> = Vec::new();
+
+        for export in exports {
+            match *export {
+                ExportSpec::Named {
+                    ref name,
+                    ref alias,
+                } => {
+                    named.push((name, alias.as_ref().map(String::as_str)));
+                }
+                ExportSpec::Default { ref name } => {
+                    default = Some(name);
+                }
+                ExportSpec::ReExport {
+                    ref module,
+                    ref name,
+                    ref alias,
+                } => {
+                    re_exported
+                        .entry(module)
+                        .or_default()
+                        .push((name, alias.as_ref().map(String::as_str)));
+                }
+                ExportSpec::ReExportAll {
+                    ref module,
+                    ref alias,
+                } => {
+                    re_exported_all.push((module, alias.as_ref().map(String::as_str)));
+                }
+            }
         }
 
         let mut out = Elements::new();
 
-        for (module, names) in collected {
-            let mut stmt = Statement::new();
+        match self.module_system {
+            ModuleSystem::Esm => {
+                if !named.is_empty() {
+                    let named: Vec<String> = named
+                        .into_iter()
+                        .map(|(name, alias)| export_name(name, alias))
+                        .collect();
 
-            stmt.push("import ");
-            stmt.push("{");
-            stmt.push(names.join(", "));
-            stmt.push("}");
-            stmt.push(" from ");
-            stmt.push(Variable::String(self.module_to_path(&module)));
-            stmt.push(";");
+                    let mut stmt = Statement::new();
+                    stmt.push("export {");
+                    stmt.push(named.join(", "));
+                    stmt.push("};");
+                    out.push(stmt);
+                }
 
-            out.push(stmt);
-        }
+                for (module, names) in re_exported {
+                    let names: Vec<String> = names
+                        .into_iter()
+                        .map(|(name, alias)| export_name(name, alias))
+                        .collect();
 
-        for (module, alias) in wildcard {
-            let mut stmt = Statement::new();
+                    let mut stmt = Statement::new();
+                    stmt.push("export {");
+                    stmt.push(names.join(", "));
+                    stmt.push("} from ");
+                    stmt.push(Variable::String(self.module_to_path(module)));
+                    stmt.push(";");
+                    out.push(stmt);
+                }
 
-            stmt.push("import * as ");
-            stmt.push(alias);
-            stmt.push(" from ");
-            stmt.push(Variable::String(self.module_to_path(&module)));
-            stmt.push(";");
+                for (module, alias) in re_exported_all {
+                    let mut stmt = Statement::new();
+                    stmt.push("export * ");
 
-            out.push(stmt);
+                    if let Some(alias) = alias {
+                        stmt.push("as ");
+                        stmt.push(alias);
+                        stmt.push(" ");
+                    }
+
+                    stmt.push("from ");
+                    stmt.push(Variable::String(self.module_to_path(module)));
+                    stmt.push(";");
+                    out.push(stmt);
+                }
+
+                if let Some(default) = default {
+                    let mut stmt = Statement::new();
+                    stmt.push("export default ");
+                    stmt.push(default);
+                    stmt.push(";");
+                    out.push(stmt);
+                }
+            }
+            ModuleSystem::CommonJs => {
+                // assigning module.exports replaces the exports object, so everything else has
+                // to be attached to it after that.
+                let target = if let Some(default) = default {
+                    out.push(format!("module.exports = {};", default).as_str());
+                    "module.exports"
+                } else {
+                    "exports"
+                };
+
+                for name in declared {
+                    out.push(format!("{}.{} = {};", target, name, name).as_str());
+                }
+
+                for (name, alias) in named {
+                    let exported = alias.unwrap_or(name);
+                    out.push(format!("{}.{} = {};", target, exported, name).as_str());
+                }
+
+                for (module, names) in re_exported {
+                    for (name, alias) in names {
+                        let mut stmt = Statement::new();
+                        stmt.push(format!("{}.{} = require(", target, alias.unwrap_or(name)));
+                        stmt.push(Variable::String(self.module_to_path(module)));
+                        stmt.push(format!(").{};", name));
+                        out.push(stmt);
+                    }
+                }
+
+                for (module, alias) in re_exported_all {
+                    let mut stmt = Statement::new();
+
+                    if let Some(alias) = alias {
+                        stmt.push(format!("{}.{} = require(", target, alias));
+                        stmt.push(Variable::String(self.module_to_path(module)));
+                        stmt.push(");");
+                    } else {
+                        stmt.push(format!("Object.assign({}, require(", target));
+                        stmt.push(Variable::String(self.module_to_path(module)));
+                        stmt.push("));");
+                    }
+
+                    out.push(stmt);
+                }
+            }
+        }
+
+        if out.is_empty() {
+            return Ok(None);
         }
 
-        Some(out)
+        Ok(Some(out))
     }
 
     pub fn format<W>(&self, out: &mut W) -> Result<()>
@@ -91,22 +318,34 @@ impl FileSpec {
     {
         let mut elements = Elements::new();
 
-        if let Some(imports) = self.imports() {
+        if let Some(imports) = self.imports()? {
             elements.push(imports);
         }
 
         elements.push(self.elements.clone().join(Spacing));
 
+        if let Some(exports) = self.exports(&self.declared())? {
+            elements.push(exports);
+        }
+
         let elements: Element = elements.join(Spacing).into();
-        let mut extra = ();
+        let mut extra = Extra::new(self.module_system);
+        extra.ascii_only = self.ascii_only;
 
         elements.format(&mut ElementFormatter::new(out), &mut extra)?;
+
         out.write_char('\n')?;
 
         Ok(())
     }
 }
 
+impl ImportReceiver for Vec<ImportedName> {
+    fn receive(&mut self, name: &ImportedName) {
+        self.push(name.clone());
+    }
+}
+
 impl ImportReceiver for BTreeSet<ImportedName> {
     fn receive(&mut self, name: &ImportedName) {
         self.insert(name.clone());
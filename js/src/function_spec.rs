@@ -5,6 +5,9 @@ pub struct FunctionSpec {
     pub name: String,
     pub arguments: Vec<Statement>,
     pub elements: Elements,
+    pub export: bool,
+    pub is_async: bool,
+    pub is_generator: bool,
 }
 
 impl FunctionSpec {
@@ -13,9 +16,26 @@ impl FunctionSpec {
             name: name.to_owned(),
             arguments: Vec::new(),
             elements: Elements::new(),
+            export: false,
+            is_async: false,
+            is_generator: false,
         }
     }
 
+    pub fn export(&mut self) {
+        self.export = true;
+    }
+
+    /// Make the function `async`.
+    pub fn asynchronous(&mut self) {
+        self.is_async = true;
+    }
+
+    /// Make the function a generator, like `function* name() {}`.
+    pub fn generator(&mut self) {
+        self.is_generator = true;
+    }
+
     pub fn push_argument<S>(&mut self, argument: S)
     where
         S: Into<Statement>,
@@ -34,7 +54,21 @@ impl FunctionSpec {
 impl From<FunctionSpec> for Element {
     fn from(value: FunctionSpec) -> Element {
         let mut open = Statement::new();
-        open.push("function ");
+
+        if value.export {
+            open.push(Variable::Export(value.name.clone()));
+        }
+
+        if value.is_async {
+            open.push("async ");
+        }
+
+        if value.is_generator {
+            open.push("function* ");
+        } else {
+            open.push("function ");
+        }
+
         open.push(value.name);
         open.push("(");
 
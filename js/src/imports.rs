@@ -1,3 +1,4 @@
+use codeviz_common::Walk;
 use super::*;
 
 pub trait ImportReceiver {
@@ -44,6 +45,22 @@ impl Imports for Variable {
             Variable::Name(ref name) => {
                 name.imports(receiver);
             }
+            Variable::Object(ref object) => {
+                object.imports(receiver);
+            }
+            Variable::Array(ref array) => {
+                array.imports(receiver);
+            }
+            Variable::Arrow(ref arrow) => {
+                arrow.imports(receiver);
+            }
+            Variable::Template(ref parts) => {
+                for part in parts {
+                    if let TemplatePart::Expression(ref stmt) = *part {
+                        stmt.imports(receiver);
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -54,7 +71,7 @@ impl Imports for Statement {
     where
         I: ImportReceiver,
     {
-        receiver.import_all(&self.parts);
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
     }
 }
 
@@ -63,18 +80,16 @@ impl Imports for Element {
     where
         I: ImportReceiver,
     {
-        match *self {
-            Push(ref statement) => {
-                statement.imports(receiver);
-            }
-            Inner(ref elements) => {
-                receiver.import_all(elements);
-            }
-            Nested(ref element) => {
-                element.imports(receiver);
-            }
-            _ => {}
-        };
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
+    }
+}
+
+impl Imports for Elements {
+    fn imports<I>(&self, receiver: &mut I)
+    where
+        I: ImportReceiver,
+    {
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
     }
 }
 
@@ -88,11 +103,54 @@ impl Imports for FunctionSpec {
     }
 }
 
-impl Imports for Elements {
+impl Imports for ObjectSpec {
     fn imports<I>(&self, receiver: &mut I)
     where
         I: ImportReceiver,
     {
-        receiver.import_all(&self.elements);
+        for entry in &self.entries {
+            match *entry {
+                ObjectEntry::Property(ref key, ref value) => {
+                    if let ObjectKey::Computed(ref key) = *key {
+                        key.imports(receiver);
+                    }
+
+                    value.imports(receiver);
+                }
+                ObjectEntry::Spread(ref value) => {
+                    value.imports(receiver);
+                }
+                ObjectEntry::Shorthand(_) => {}
+            }
+        }
+    }
+}
+
+impl Imports for ArraySpec {
+    fn imports<I>(&self, receiver: &mut I)
+    where
+        I: ImportReceiver,
+    {
+        for entry in &self.entries {
+            match *entry {
+                ArrayEntry::Value(ref value) | ArrayEntry::Spread(ref value) => {
+                    value.imports(receiver);
+                }
+            }
+        }
+    }
+}
+
+impl Imports for ArrowFunctionSpec {
+    fn imports<I>(&self, receiver: &mut I)
+    where
+        I: ImportReceiver,
+    {
+        receiver.import_all(&self.arguments);
+
+        match self.body {
+            ArrowBody::Expression(ref stmt) => stmt.imports(receiver),
+            ArrowBody::Block(ref elements) => elements.imports(receiver),
+        }
     }
 }
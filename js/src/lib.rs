@@ -1,23 +1,47 @@
 extern crate codeviz_common;
 
+mod array_spec;
+mod arrow_function_spec;
 mod class_spec;
 mod constructor_spec;
+mod export_spec;
+mod extra;
+mod field_spec;
 mod file_spec;
 mod function_spec;
 mod imports;
 mod method_spec;
+mod module_imports;
+mod module_resolver;
 mod name;
+mod object_spec;
+mod parameter_spec;
+mod pattern;
 mod variable;
+mod variable_decl_spec;
+mod visitor;
 
 pub use codeviz_common::Element::*;
+pub use self::array_spec::*;
+pub use self::arrow_function_spec::*;
 pub use self::class_spec::*;
 pub use self::constructor_spec::*;
+pub use self::export_spec::*;
+pub use self::extra::*;
+pub use self::field_spec::*;
 pub use self::file_spec::*;
 pub use self::function_spec::*;
 pub use self::imports::*;
 pub use self::method_spec::*;
+pub use self::module_imports::*;
+pub use self::module_resolver::*;
 pub use self::name::*;
+pub use self::object_spec::*;
+pub use self::parameter_spec::*;
+pub use self::pattern::*;
 pub use self::variable::*;
+pub use self::variable_decl_spec::*;
+pub use self::visitor::*;
 pub(crate) use self::errors::*;
 
 pub mod errors {
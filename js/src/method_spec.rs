@@ -1,11 +1,25 @@
 use super::*;
 
+/// The kind of a method in a class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MethodKind {
+    /// A regular method, like `name() {}`.
+    Method,
+    /// A getter, like `get name() {}`.
+    Getter,
+    /// A setter, like `set name(value) {}`.
+    Setter,
+}
+
 #[derive(Debug, Clone)]
 pub struct MethodSpec {
     pub name: String,
     pub arguments: Vec<Statement>,
     pub elements: Elements,
+    pub kind: MethodKind,
     pub is_static: bool,
+    pub is_async: bool,
+    pub is_generator: bool,
 }
 
 impl MethodSpec {
@@ -14,19 +28,44 @@ impl MethodSpec {
             name: name.to_owned(),
             arguments: Vec::new(),
             elements: Elements::new(),
+            kind: MethodKind::Method,
             is_static: false,
+            is_async: false,
+            is_generator: false,
         }
     }
 
     pub fn with_static(name: &str) -> MethodSpec {
         MethodSpec {
-            name: name.to_owned(),
-            arguments: Vec::new(),
-            elements: Elements::new(),
             is_static: true,
+            ..MethodSpec::new(name)
+        }
+    }
+
+    pub fn getter(name: &str) -> MethodSpec {
+        MethodSpec {
+            kind: MethodKind::Getter,
+            ..MethodSpec::new(name)
         }
     }
 
+    pub fn setter(name: &str) -> MethodSpec {
+        MethodSpec {
+            kind: MethodKind::Setter,
+            ..MethodSpec::new(name)
+        }
+    }
+
+    /// Make the method `async`.
+    pub fn asynchronous(&mut self) {
+        self.is_async = true;
+    }
+
+    /// Make the method a generator, like `*name() {}`.
+    pub fn generator(&mut self) {
+        self.is_generator = true;
+    }
+
     pub fn push_argument<S>(&mut self, argument: S)
     where
         S: Into<Statement>,
@@ -56,6 +95,20 @@ impl From<MethodSpec> for Element {
             open.push("static ");
         }
 
+        match value.kind {
+            MethodKind::Method => {}
+            MethodKind::Getter => open.push("get "),
+            MethodKind::Setter => open.push("set "),
+        }
+
+        if value.is_async {
+            open.push("async ");
+        }
+
+        if value.is_generator {
+            open.push("*");
+        }
+
         open.push(value.name);
         open.push("(");
         open.push(arguments.join(", "));
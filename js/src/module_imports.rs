@@ -0,0 +1,160 @@
+use super::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Everything imported from a single module.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleImports {
+    /// Local names bound to the default export.
+    pub defaults: BTreeSet<String>,
+    /// Aliases bound to the whole module.
+    pub namespaces: BTreeSet<String>,
+    /// Specific names imported, and their local name if renamed.
+    pub named: BTreeSet<(String, Option<String>)>,
+    /// If the module is imported for its side effects.
+    pub side_effect: bool,
+}
+
+impl ModuleImports {
+    /// Group imported names by the module that they are imported from.
+    ///
+    /// Fails if the same local name is bound by imports of different things.
+    pub fn group<'a, I>(imports: I) -> Result<BTreeMap<String, ModuleImports>>
+    where
+        I: IntoIterator<Item = &'a ImportedName>,
+    {
+        let mut locals: HashMap<String, (&str, &ImportKind, &str)> = HashMap::new();
+        let mut out: BTreeMap<String, ModuleImports> = BTreeMap::new();
+
+        for import in imports {
+            if let Some(local) = import.local() {
+                // what the local name is bound to.
+                let bound = match import.kind {
+                    ImportKind::Namespace(_) => (import.module.as_str(), &import.kind, ""),
+                    ImportKind::Renamed(_) => {
+                        (import.module.as_str(), &ImportKind::Named, import.name.as_str())
+                    }
+                    _ => (import.module.as_str(), &import.kind, import.name.as_str()),
+                };
+
+                if let Some(existing) = locals.insert(local.to_owned(), bound) {
+                    if existing != bound {
+                        return Err(ErrorKind::ImportCollision(local.to_owned()).into());
+                    }
+                }
+            }
+
+            let module = out.entry(import.module.clone()).or_default();
+
+            match import.kind {
+                ImportKind::Named => {
+                    module.named.insert((import.name.clone(), None));
+                }
+                ImportKind::Renamed(ref local) => {
+                    module.named.insert((import.name.clone(), Some(local.clone())));
+                }
+                ImportKind::Default => {
+                    module.defaults.insert(import.name.clone());
+                }
+                ImportKind::Namespace(ref alias) => {
+                    module.namespaces.insert(alias.clone());
+                }
+                ImportKind::SideEffect => {
+                    module.side_effect = true;
+                }
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Modules which are imported for their side effects, in the order they were first imported.
+    ///
+    /// Side effects often depend on each other, like polyfills, so their order is preserved.
+    pub fn side_effects<'a, I>(imports: I) -> Vec<String>
+    where
+        I: IntoIterator<Item = &'a ImportedName>,
+    {
+        let mut out: Vec<String> = Vec::new();
+
+        for import in imports {
+            if let ImportKind::SideEffect = import.kind {
+                if !out.contains(&import.module) {
+                    out.push(import.module.clone());
+                }
+            }
+        }
+
+        out
+    }
+
+    /// If this module binds any local names, which also runs its side effects.
+    pub fn has_bindings(&self) -> bool {
+        !self.defaults.is_empty() || !self.namespaces.is_empty() || !self.named.is_empty()
+    }
+
+    /// Build the clauses of `import` statements for this module, like `React, {useState}`.
+    ///
+    /// As few statements as possible are used, and an empty list means that the module is
+    /// only imported for its side effects.
+    pub fn clauses(&self) -> Vec<String> {
+        let mut defaults = self.defaults.iter();
+        let mut namespaces = self.namespaces.iter();
+
+        let mut named: Vec<String> = self.named
+            .iter()
+            .map(|(name, local)| match *local {
+                Some(ref local) => format!("{} as {}", name, local),
+                None => name.clone(),
+            })
+            .collect();
+
+        let mut out = Vec::new();
+
+        loop {
+            let mut parts = Vec::new();
+
+            if let Some(default) = defaults.next() {
+                parts.push(default.clone());
+            }
+
+            // named imports and namespaces can't be mixed in one statement, so namespaces are
+            // paired with defaults first.
+            if let Some(namespace) = namespaces.next() {
+                parts.push(format!("* as {}", namespace));
+            } else if !named.is_empty() {
+                parts.push(format!("{{{}}}", named.join(", ")));
+                named.clear();
+            }
+
+            if parts.is_empty() {
+                break;
+            }
+
+            out.push(parts.join(", "));
+        }
+
+        out
+    }
+
+    /// Build the bindings of `require` statements for this module, like `{hello, a: b}`.
+    ///
+    /// An empty list means that the module is only imported for its side effects.
+    pub fn bindings(&self) -> Vec<String> {
+        let mut out: Vec<String> = self.defaults.iter().cloned().collect();
+        out.extend(self.namespaces.iter().cloned());
+
+        if !self.named.is_empty() {
+            let named: Vec<String> = self.named
+                .iter()
+                .map(|(name, local)| match *local {
+                    Some(ref local) => format!("{}: {}", name, local),
+                    None => name.clone(),
+                })
+                .collect();
+
+            out.push(format!("{{{}}}", named.join(", ")));
+        }
+
+        out
+    }
+}
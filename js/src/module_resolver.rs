@@ -0,0 +1,172 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::rc::Rc;
+
+/// Resolves module names into the specifiers used by `import` and `export` statements.
+pub trait ModuleResolver: fmt::Debug {
+    fn resolve(&self, module: &str) -> String;
+}
+
+/// Check if a module already is a specifier, like a scoped package (`@org/pkg`) or a relative
+/// or absolute path, which has to be used verbatim.
+fn is_specifier(module: &str) -> bool {
+    module.starts_with('@') || module.starts_with("./") || module.starts_with("../") ||
+        module.starts_with('/')
+}
+
+/// Extension policy for resolved paths.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Extension {
+    /// Leave paths without an extension, like `foo/bar`.
+    None,
+    /// Add a `.js` extension, like `foo/bar.js`.
+    Js,
+    /// Add the given extension, like `foo/bar.mjs` for `Custom("mjs")`.
+    Custom(String),
+}
+
+impl Extension {
+    fn apply(&self, path: String) -> String {
+        match *self {
+            Extension::None => path,
+            Extension::Js => format!("{}.js", path),
+            Extension::Custom(ref extension) => format!("{}.{}", path, extension),
+        }
+    }
+}
+
+/// Resolves `foo.bar` into `foo/bar.js`.
+///
+/// Scoped packages and modules that already are relative or absolute paths are used verbatim.
+#[derive(Debug, Clone)]
+pub struct PathResolver {
+    pub extension: Extension,
+}
+
+impl PathResolver {
+    pub fn new(extension: Extension) -> PathResolver {
+        PathResolver { extension: extension }
+    }
+}
+
+impl Default for PathResolver {
+    fn default() -> PathResolver {
+        PathResolver::new(Extension::Js)
+    }
+}
+
+impl ModuleResolver for PathResolver {
+    fn resolve(&self, module: &str) -> String {
+        if is_specifier(module) {
+            return module.to_owned();
+        }
+
+        let parts: Vec<&str> = module.split('.').collect();
+        self.extension.apply(parts.join("/"))
+    }
+}
+
+/// Resolves modules into paths relative to the module of the importing file.
+///
+/// Importing `foo.bar` from `foo.baz` resolves to `./bar.js`, and `util` to `../util.js`.
+/// Scoped packages and modules that already are relative or absolute paths are used verbatim.
+#[derive(Debug, Clone)]
+pub struct RelativeResolver {
+    pub from: String,
+    pub extension: Extension,
+}
+
+impl RelativeResolver {
+    pub fn new(from: &str, extension: Extension) -> RelativeResolver {
+        RelativeResolver {
+            from: from.to_owned(),
+            extension: extension,
+        }
+    }
+}
+
+impl ModuleResolver for RelativeResolver {
+    fn resolve(&self, module: &str) -> String {
+        if is_specifier(module) {
+            return module.to_owned();
+        }
+
+        let from: Vec<&str> = self.from.split('.').collect();
+        let from = &from[..from.len() - 1];
+
+        let target: Vec<&str> = module.split('.').collect();
+
+        let common = from.iter()
+            .zip(&target[..target.len() - 1])
+            .take_while(|&(a, b)| a == b)
+            .count();
+
+        let mut path = String::new();
+
+        if from.len() == common {
+            path.push_str("./");
+        } else {
+            for _ in common..from.len() {
+                path.push_str("../");
+            }
+        }
+
+        path.push_str(&target[common..].join("/"));
+        self.extension.apply(path)
+    }
+}
+
+/// Uses module names verbatim, like `lodash`, `@org/pkg`, or `./util`.
+#[derive(Debug, Clone, Default)]
+pub struct BareResolver;
+
+impl ModuleResolver for BareResolver {
+    fn resolve(&self, module: &str) -> String {
+        module.to_owned()
+    }
+}
+
+/// Uses the names of known packages verbatim, and delegates all other modules to a fallback.
+///
+/// Scoped packages (`@org/pkg`) and modules that already are relative or absolute paths are
+/// always used verbatim.
+#[derive(Debug, Clone)]
+pub struct PackageResolver {
+    pub packages: BTreeSet<String>,
+    pub fallback: Rc<dyn ModuleResolver>,
+}
+
+impl PackageResolver {
+    pub fn new<R>(fallback: R) -> PackageResolver
+    where
+        R: ModuleResolver + 'static,
+    {
+        PackageResolver {
+            packages: BTreeSet::new(),
+            fallback: Rc::new(fallback),
+        }
+    }
+
+    pub fn push_package(&mut self, package: &str) {
+        self.packages.insert(package.to_owned());
+    }
+
+    fn is_package(&self, module: &str) -> bool {
+        if is_specifier(module) {
+            return true;
+        }
+
+        let package = module.split('/').next().unwrap_or(module);
+        self.packages.contains(package)
+    }
+}
+
+impl ModuleResolver for PackageResolver {
+    fn resolve(&self, module: &str) -> String {
+        if self.is_package(module) {
+            return module.to_owned();
+        }
+
+        self.fallback.resolve(module)
+    }
+}
@@ -13,15 +13,46 @@ impl Name {
         ImportedName {
             module: module.to_owned(),
             name: name.to_owned(),
-            alias: None,
+            kind: ImportKind::Named,
         }
     }
 
+    /// Import all names of the module as `alias`, and refer to this name as `alias.name`.
     pub fn imported_alias(module: &str, name: &str, alias: &str) -> ImportedName {
         ImportedName {
             module: module.to_owned(),
             name: name.to_owned(),
-            alias: Some(alias.to_owned()),
+            kind: ImportKind::Namespace(alias.to_owned()),
+        }
+    }
+
+    /// Import the name under a different local name, like `import {name as local}`.
+    pub fn imported_as(module: &str, name: &str, local: &str) -> ImportedName {
+        ImportedName {
+            module: module.to_owned(),
+            name: name.to_owned(),
+            kind: ImportKind::Renamed(local.to_owned()),
+        }
+    }
+
+    /// Import the default export of the module as `name`.
+    pub fn imported_default(module: &str, name: &str) -> ImportedName {
+        ImportedName {
+            module: module.to_owned(),
+            name: name.to_owned(),
+            kind: ImportKind::Default,
+        }
+    }
+
+    /// Import a module only for its side effects, like `import "./polyfill"`.
+    ///
+    /// Since this doesn't bind any names, it is typically registered with
+    /// `FileSpec::push_import`.
+    pub fn side_effect(module: &str) -> ImportedName {
+        ImportedName {
+            module: module.to_owned(),
+            name: String::new(),
+            kind: ImportKind::SideEffect,
         }
     }
 
@@ -39,10 +70,13 @@ impl Name {
     {
         match *self {
             Name::Imported(ref imported) => {
-                if let Some(ref alias) = imported.alias {
-                    write!(out, "{}.{}", alias, imported.name.clone())
-                } else {
-                    out.write_str(&imported.name)
+                match imported.kind {
+                    ImportKind::Namespace(ref alias) => {
+                        write!(out, "{}.{}", alias, imported.name.clone())
+                    }
+                    ImportKind::Renamed(ref local) => out.write_str(local),
+                    ImportKind::SideEffect => Ok(()),
+                    _ => out.write_str(&imported.name),
                 }
             }
             Name::BuiltIn(ref built_in) => out.write_str(&built_in.name),
@@ -53,11 +87,38 @@ impl Name {
     }
 }
 
+/// How a name is imported from its module.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ImportKind {
+    /// `import {name} from "module"`
+    Named,
+    /// `import {name as local} from "module"`
+    Renamed(String),
+    /// `import name from "module"`
+    Default,
+    /// `import * as alias from "module"`, where the name is referenced as `alias.name`.
+    Namespace(String),
+    /// `import "module"`
+    SideEffect,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ImportedName {
     pub module: String,
     pub name: String,
-    pub alias: Option<String>,
+    pub kind: ImportKind,
+}
+
+impl ImportedName {
+    /// The local name bound by importing this name, if any.
+    pub fn local(&self) -> Option<&str> {
+        match self.kind {
+            ImportKind::Named | ImportKind::Default => Some(&self.name),
+            ImportKind::Renamed(ref local) => Some(local),
+            ImportKind::Namespace(ref alias) => Some(alias),
+            ImportKind::SideEffect => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,229 @@
+use super::*;
+use codeviz_common::{ElementFormat, ElementFormatter};
+
+/// Width at which object and array literals are broken up into one entry per line.
+pub const DEFAULT_WIDTH: usize = 80;
+
+/// Key of a property in an object literal.
+#[derive(Debug, Clone)]
+pub enum ObjectKey {
+    /// A static key, which is quoted if it isn't a valid identifier.
+    Name(String),
+    /// A computed key, like `[Symbol.iterator]`.
+    Computed(Statement),
+}
+
+/// A single entry in an object literal.
+#[derive(Debug, Clone)]
+pub enum ObjectEntry {
+    /// A property, like `key: value`.
+    Property(ObjectKey, Statement),
+    /// A shorthand property, like `{name}`.
+    Shorthand(String),
+    /// A spread, like `...other`.
+    Spread(Statement),
+}
+
+/// An object literal, like `{a: 1, "b-c": 2}`.
+#[derive(Debug, Clone)]
+pub struct ObjectSpec {
+    pub entries: Vec<ObjectEntry>,
+    pub width: usize,
+}
+
+impl ObjectSpec {
+    pub fn new() -> ObjectSpec {
+        ObjectSpec {
+            entries: Vec::new(),
+            width: DEFAULT_WIDTH,
+        }
+    }
+
+    /// Set the width above which entries are put on separate lines.
+    pub fn width(&mut self, width: usize) {
+        self.width = width;
+    }
+
+    pub fn push<S>(&mut self, key: &str, value: S)
+    where
+        S: Into<Statement>,
+    {
+        self.entries.push(ObjectEntry::Property(
+            ObjectKey::Name(key.to_owned()),
+            value.into(),
+        ));
+    }
+
+    pub fn push_computed<K, S>(&mut self, key: K, value: S)
+    where
+        K: Into<Statement>,
+        S: Into<Statement>,
+    {
+        self.entries.push(ObjectEntry::Property(
+            ObjectKey::Computed(key.into()),
+            value.into(),
+        ));
+    }
+
+    pub fn push_shorthand(&mut self, name: &str) {
+        self.entries.push(ObjectEntry::Shorthand(name.to_owned()));
+    }
+
+    pub fn push_spread<S>(&mut self, value: S)
+    where
+        S: Into<Statement>,
+    {
+        self.entries.push(ObjectEntry::Spread(value.into()));
+    }
+
+    pub fn format<E>(&self, out: &mut E, depth: usize, extra: &mut Extra) -> Result<()>
+    where
+        E: ElementFormat,
+    {
+        let entries: Vec<Statement> = self.entries.iter().map(entry_statement).collect();
+        format_entries(out, depth, extra, ('{', '}'), &entries, self.width)
+    }
+}
+
+fn entry_statement(entry: &ObjectEntry) -> Statement {
+    let mut out = Statement::new();
+
+    match *entry {
+        ObjectEntry::Property(ref key, ref value) => {
+            match *key {
+                ObjectKey::Name(ref name) if is_identifier(name) => {
+                    out.push(name.as_str());
+                }
+                ObjectKey::Name(ref name) => {
+                    out.push(Variable::String(name.clone()));
+                }
+                ObjectKey::Computed(ref key) => {
+                    out.push("[");
+                    out.push(key.clone());
+                    out.push("]");
+                }
+            }
+
+            out.push(": ");
+            out.push(value.clone());
+        }
+        ObjectEntry::Shorthand(ref name) => {
+            out.push(name.as_str());
+        }
+        ObjectEntry::Spread(ref value) => {
+            out.push("...");
+            out.push(value.clone());
+        }
+    }
+
+    out
+}
+
+/// Check if the key can be used in an object literal without quoting.
+///
+/// This is the case for identifiers, including reserved words, and canonical array indexes.
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+
+    match chars.next() {
+        Some(c) if c.is_ascii_digit() => {
+            return key == "0" || (c != '0' && key.chars().all(|c| c.is_ascii_digit()));
+        }
+        Some(c) if c.is_alphabetic() || c == '_' || c == '$' => {}
+        _ => return false,
+    }
+
+    chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Format the entries of an object or array literal.
+///
+/// Entries are put on a single line, unless that line would be longer than `width` or one of
+/// the entries spans multiple lines.
+pub(crate) fn format_entries<E>(
+    out: &mut E,
+    depth: usize,
+    extra: &mut Extra,
+    (open, close): (char, char),
+    entries: &[Statement],
+    width: usize,
+) -> Result<()>
+where
+    E: ElementFormat,
+{
+    let mut compact = String::new();
+
+    {
+        let mut formatter = ElementFormatter::new(&mut compact);
+        let mut extra = Extra {
+            ascii_only: extra.ascii_only,
+            ..Extra::new(extra.module_system)
+        };
+        format_compact(&mut formatter, depth, &mut extra, (open, close), entries)?;
+    }
+
+    if compact.len() <= width && !compact.contains('\n') {
+        return format_compact(out, depth, extra, (open, close), entries);
+    }
+
+    out.write_char(open)?;
+    out.indent();
+
+    for entry in entries {
+        out.new_line()?;
+        entry.format(out, depth, extra)?;
+        out.write_char(',')?;
+    }
+
+    out.unindent();
+    out.new_line()?;
+    out.write_char(close)?;
+    Ok(())
+}
+
+fn format_compact<E>(
+    out: &mut E,
+    depth: usize,
+    extra: &mut Extra,
+    (open, close): (char, char),
+    entries: &[Statement],
+) -> Result<()>
+where
+    E: ElementFormat,
+{
+    out.write_char(open)?;
+
+    let mut it = entries.iter().peekable();
+
+    while let Some(entry) = it.next() {
+        entry.format(out, depth, extra)?;
+
+        if it.peek().is_some() {
+            out.write_str(", ")?;
+        }
+    }
+
+    out.write_char(close)?;
+    Ok(())
+}
+
+impl<'a, T> From<&'a T> for ObjectSpec
+where
+    T: Into<ObjectSpec> + Clone,
+{
+    fn from(value: &'a T) -> ObjectSpec {
+        value.clone().into()
+    }
+}
+
+impl From<ObjectSpec> for Variable {
+    fn from(value: ObjectSpec) -> Variable {
+        Variable::Object(value)
+    }
+}
+
+impl From<ObjectSpec> for Statement {
+    fn from(value: ObjectSpec) -> Statement {
+        Variable::Object(value).into()
+    }
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+/// A parameter of a function, like `a = 1` or `...rest`.
+#[derive(Debug, Clone)]
+pub struct ParameterSpec {
+    pub pattern: Pattern,
+    pub default: Option<Statement>,
+    pub rest: bool,
+}
+
+impl ParameterSpec {
+    pub fn new<P>(pattern: P) -> ParameterSpec
+    where
+        P: Into<Pattern>,
+    {
+        ParameterSpec {
+            pattern: pattern.into(),
+            default: None,
+            rest: false,
+        }
+    }
+
+    /// A rest parameter, which collects all remaining arguments.
+    pub fn rest<P>(pattern: P) -> ParameterSpec
+    where
+        P: Into<Pattern>,
+    {
+        ParameterSpec {
+            pattern: pattern.into(),
+            default: None,
+            rest: true,
+        }
+    }
+
+    pub fn default<S>(&mut self, default: S)
+    where
+        S: Into<Statement>,
+    {
+        self.default = Some(default.into());
+    }
+}
+
+impl<'a, T> From<&'a T> for ParameterSpec
+where
+    T: Into<ParameterSpec> + Clone,
+{
+    fn from(value: &'a T) -> ParameterSpec {
+        value.clone().into()
+    }
+}
+
+impl From<ParameterSpec> for Statement {
+    fn from(value: ParameterSpec) -> Statement {
+        let mut out = Statement::new();
+
+        if value.rest {
+            out.push("...");
+        }
+
+        out.push(Statement::from(value.pattern));
+
+        if let Some(default) = value.default {
+            out.push(" = ");
+            out.push(default);
+        }
+
+        out
+    }
+}
@@ -0,0 +1,219 @@
+use super::*;
+
+/// A binding pattern, as used in declarations and parameters.
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    /// A plain binding, like `a`.
+    Name(String),
+    /// An object destructuring pattern, like `{a, b: c = 1, ...rest}`.
+    Object(ObjectPattern),
+    /// An array destructuring pattern, like `[a, , b = 1, ...rest]`.
+    Array(ArrayPattern),
+}
+
+/// A property in an object destructuring pattern.
+#[derive(Debug, Clone)]
+pub struct PatternProperty {
+    pub key: String,
+    pub target: Option<Pattern>,
+    pub default: Option<Statement>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ObjectPattern {
+    pub properties: Vec<PatternProperty>,
+    pub rest: Option<String>,
+}
+
+impl ObjectPattern {
+    pub fn new() -> ObjectPattern {
+        ObjectPattern {
+            properties: Vec::new(),
+            rest: None,
+        }
+    }
+
+    /// Bind the property to a local of the same name, like `{key}`.
+    pub fn push(&mut self, key: &str) {
+        self.properties.push(PatternProperty {
+            key: key.to_owned(),
+            target: None,
+            default: None,
+        });
+    }
+
+    /// Bind the property to another pattern, like `{key: target}`.
+    pub fn push_target<P>(&mut self, key: &str, target: P)
+    where
+        P: Into<Pattern>,
+    {
+        self.properties.push(PatternProperty {
+            key: key.to_owned(),
+            target: Some(target.into()),
+            default: None,
+        });
+    }
+
+    /// Bind the property with a default value, like `{key = default}`.
+    pub fn push_default<S>(&mut self, key: &str, default: S)
+    where
+        S: Into<Statement>,
+    {
+        self.properties.push(PatternProperty {
+            key: key.to_owned(),
+            target: None,
+            default: Some(default.into()),
+        });
+    }
+
+    pub fn push_property(&mut self, property: PatternProperty) {
+        self.properties.push(property);
+    }
+
+    /// Bind the remaining properties, like `{...rest}`.
+    pub fn rest(&mut self, name: &str) {
+        self.rest = Some(name.to_owned());
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ArrayPattern {
+    /// Bound elements, where `None` skips an element.
+    pub elements: Vec<Option<(Pattern, Option<Statement>)>>,
+    pub rest: Option<Box<Pattern>>,
+}
+
+impl ArrayPattern {
+    pub fn new() -> ArrayPattern {
+        ArrayPattern {
+            elements: Vec::new(),
+            rest: None,
+        }
+    }
+
+    pub fn push<P>(&mut self, pattern: P)
+    where
+        P: Into<Pattern>,
+    {
+        self.elements.push(Some((pattern.into(), None)));
+    }
+
+    pub fn push_default<P, S>(&mut self, pattern: P, default: S)
+    where
+        P: Into<Pattern>,
+        S: Into<Statement>,
+    {
+        self.elements.push(Some((pattern.into(), Some(default.into()))));
+    }
+
+    /// Skip an element, like `[, b]`.
+    pub fn push_hole(&mut self) {
+        self.elements.push(None);
+    }
+
+    /// Bind the remaining elements, like `[...rest]`.
+    pub fn rest<P>(&mut self, pattern: P)
+    where
+        P: Into<Pattern>,
+    {
+        self.rest = Some(Box::new(pattern.into()));
+    }
+}
+
+impl<'a, T> From<&'a T> for Pattern
+where
+    T: Into<Pattern> + Clone,
+{
+    fn from(value: &'a T) -> Pattern {
+        value.clone().into()
+    }
+}
+
+impl<'a> From<&'a str> for Pattern {
+    fn from(value: &'a str) -> Pattern {
+        Pattern::Name(value.to_owned())
+    }
+}
+
+impl From<ObjectPattern> for Pattern {
+    fn from(value: ObjectPattern) -> Pattern {
+        Pattern::Object(value)
+    }
+}
+
+impl From<ArrayPattern> for Pattern {
+    fn from(value: ArrayPattern) -> Pattern {
+        Pattern::Array(value)
+    }
+}
+
+impl From<Pattern> for Statement {
+    fn from(value: Pattern) -> Statement {
+        let mut out = Statement::new();
+
+        match value {
+            Pattern::Name(name) => {
+                out.push(name);
+            }
+            Pattern::Object(object) => {
+                let mut entries = Statement::new();
+
+                for property in object.properties {
+                    let mut entry = Statement::new();
+                    entry.push(property.key);
+
+                    if let Some(target) = property.target {
+                        entry.push(": ");
+                        entry.push(Statement::from(target));
+                    }
+
+                    if let Some(default) = property.default {
+                        entry.push(" = ");
+                        entry.push(default);
+                    }
+
+                    entries.push(entry);
+                }
+
+                if let Some(rest) = object.rest {
+                    entries.push(format!("...{}", rest));
+                }
+
+                out.push("{");
+                out.push(entries.join(", "));
+                out.push("}");
+            }
+            Pattern::Array(array) => {
+                let mut entries = Statement::new();
+
+                for element in array.elements {
+                    let mut entry = Statement::new();
+
+                    if let Some((pattern, default)) = element {
+                        entry.push(Statement::from(pattern));
+
+                        if let Some(default) = default {
+                            entry.push(" = ");
+                            entry.push(default);
+                        }
+                    }
+
+                    entries.push(entry);
+                }
+
+                if let Some(rest) = array.rest {
+                    let mut entry = Statement::new();
+                    entry.push("...");
+                    entry.push(Statement::from(*rest));
+                    entries.push(entry);
+                }
+
+                out.push("[");
+                out.push(entries.join(", "));
+                out.push("]");
+            }
+        }
+
+        out
+    }
+}
@@ -13,18 +13,28 @@ pub enum Variable {
     Statement(Statement),
     /// A name that will be appended.
     Name(Name),
+    /// Marks the declaration with the given name as exported.
+    Export(String),
+    /// An object literal.
+    Object(ObjectSpec),
+    /// An array literal.
+    Array(ArraySpec),
+    /// An arrow function.
+    Arrow(ArrowFunctionSpec),
+    /// A template literal, like `` `hello ${name}` ``.
+    Template(Vec<TemplatePart>),
 }
 
 impl VariableFormat for Variable {
-    type Extra = ();
+    type Extra = Extra;
 
-    fn format<E>(&self, out: &mut E, depth: usize, extra: &mut ()) -> Result<()>
+    fn format<E>(&self, out: &mut E, depth: usize, extra: &mut Extra) -> Result<()>
     where
         E: ElementFormat,
     {
         match *self {
             Variable::String(ref string) => {
-                quote_string(out, string)?;
+                write_quoted(out, string, extra.ascii_only)?;
             }
             Variable::Statement(ref stmt) => {
                 stmt.format(out, depth, extra)?;
@@ -35,6 +45,24 @@ impl VariableFormat for Variable {
             Variable::Name(ref name) => {
                 name.format(out)?;
             }
+            Variable::Export(_) => {
+                // CommonJS exports are assigned at the end of the file.
+                if extra.module_system == ModuleSystem::Esm {
+                    out.write_str("export ")?;
+                }
+            }
+            Variable::Object(ref object) => {
+                object.format(out, depth, extra)?;
+            }
+            Variable::Array(ref array) => {
+                array.format(out, depth, extra)?;
+            }
+            Variable::Arrow(ref arrow) => {
+                arrow.format(out, depth, extra)?;
+            }
+            Variable::Template(ref parts) => {
+                format_template(out, parts, depth, extra)?;
+            }
         }
 
         Ok(())
@@ -92,28 +120,147 @@ impl From<LocalName> for Variable {
     }
 }
 
+/// A part of a template literal.
+#[derive(Debug, Clone)]
+pub enum TemplatePart {
+    /// Text that will be escaped and appended.
+    Text(String),
+    /// A statement that will be interpolated, like `${value}`.
+    Expression(Statement),
+}
+
+impl<'a> From<&'a str> for TemplatePart {
+    fn from(value: &'a str) -> TemplatePart {
+        TemplatePart::Text(value.to_owned())
+    }
+}
+
+impl From<Statement> for TemplatePart {
+    fn from(value: Statement) -> TemplatePart {
+        TemplatePart::Expression(value)
+    }
+}
+
 /// Quote a string to make it suitable as a literal JavaScript string.
 pub fn quote_string<E>(out: &mut E, input: &str) -> Result<()>
+where
+    E: ElementFormat,
+{
+    write_quoted(out, input, false)
+}
+
+/// Quote a string like `quote_string`, but escape all characters which are not printable ASCII.
+pub fn quote_string_ascii<E>(out: &mut E, input: &str) -> Result<()>
+where
+    E: ElementFormat,
+{
+    write_quoted(out, input, true)
+}
+
+fn write_quoted<E>(out: &mut E, input: &str, ascii_only: bool) -> Result<()>
 where
     E: ElementFormat,
 {
     out.write_char('"')?;
 
     for c in input.chars() {
-        match c {
-            '\t' => out.write_str("\\t"),
-            '\u{0007}' => out.write_str("\\b"),
-            '\n' => out.write_str("\\n"),
-            '\r' => out.write_str("\\r"),
-            '\u{0014}' => out.write_str("\\f"),
-            '\'' => out.write_str("\\'"),
-            '"' => out.write_str("\\\""),
-            '\\' => out.write_str("\\\\"),
-            c => out.write_char(c),
-        }?;
+        escape_char(out, c, '"', ascii_only)?;
     }
 
     out.write_char('"')?;
 
     Ok(())
 }
+
+fn format_template<E>(
+    out: &mut E,
+    parts: &[TemplatePart],
+    depth: usize,
+    extra: &mut Extra,
+) -> Result<()>
+where
+    E: ElementFormat,
+{
+    out.write_char('`')?;
+
+    // adjacent text parts are escaped together, since `$` and `{` might be split between them.
+    let mut text = String::new();
+
+    for part in parts {
+        match *part {
+            TemplatePart::Text(ref value) => {
+                text.push_str(value);
+            }
+            TemplatePart::Expression(ref stmt) => {
+                format_template_text(out, &text, extra)?;
+                text.clear();
+
+                out.write_str("${")?;
+                stmt.format(out, depth, extra)?;
+                out.write_char('}')?;
+            }
+        }
+    }
+
+    format_template_text(out, &text, extra)?;
+    out.write_char('`')?;
+
+    Ok(())
+}
+
+fn format_template_text<E>(out: &mut E, text: &str, extra: &Extra) -> Result<()>
+where
+    E: ElementFormat,
+{
+    let mut it = text.chars().peekable();
+
+    while let Some(c) = it.next() {
+        // `${` would start an interpolation.
+        if c == '$' && it.peek() == Some(&'{') {
+            out.write_str("\\$")?;
+            continue;
+        }
+
+        escape_char(out, c, '`', extra.ascii_only)?;
+    }
+
+    Ok(())
+}
+
+/// Escape a single character inside of a literal delimited by `quote`.
+fn escape_char<E>(out: &mut E, c: char, quote: char, ascii_only: bool) -> Result<()>
+where
+    E: ElementFormat,
+{
+    match c {
+        '\u{0008}' => out.write_str("\\b")?,
+        '\t' => out.write_str("\\t")?,
+        '\n' => out.write_str("\\n")?,
+        '\u{000b}' => out.write_str("\\v")?,
+        '\u{000c}' => out.write_str("\\f")?,
+        '\r' => out.write_str("\\r")?,
+        '\\' => out.write_str("\\\\")?,
+        // line terminators are not permitted in string literals before ES2019.
+        '\u{2028}' | '\u{2029}' => write!(out, "\\u{:04x}", c as u32)?,
+        c if c == quote => {
+            out.write_char('\\')?;
+            out.write_char(c)?;
+        }
+        c if (c as u32) < 0x20 => write!(out, "\\x{:02x}", c as u32)?,
+        c if ascii_only && (c as u32) > 0x7e => {
+            if (c as u32) <= 0xff {
+                write!(out, "\\x{:02x}", c as u32)?;
+            } else {
+                // characters outside of the BMP are escaped as surrogate pairs.
+                let mut buf = [0u16; 2];
+
+                for unit in c.encode_utf16(&mut buf) {
+                    write!(out, "\\u{:04x}", unit)?;
+                }
+            }
+        }
+        c => out.write_char(c)?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use super::*;
+
+/// The keyword used to declare a variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeclarationKind {
+    Const,
+    Let,
+}
+
+/// A variable declaration, like `const {a, b} = value;`.
+#[derive(Debug, Clone)]
+pub struct VariableDeclSpec {
+    pub kind: DeclarationKind,
+    pub pattern: Pattern,
+    pub initializer: Option<Statement>,
+}
+
+impl VariableDeclSpec {
+    pub fn constant<P, S>(pattern: P, initializer: S) -> VariableDeclSpec
+    where
+        P: Into<Pattern>,
+        S: Into<Statement>,
+    {
+        VariableDeclSpec {
+            kind: DeclarationKind::Const,
+            pattern: pattern.into(),
+            initializer: Some(initializer.into()),
+        }
+    }
+
+    pub fn with_let<P>(pattern: P) -> VariableDeclSpec
+    where
+        P: Into<Pattern>,
+    {
+        VariableDeclSpec {
+            kind: DeclarationKind::Let,
+            pattern: pattern.into(),
+            initializer: None,
+        }
+    }
+
+    pub fn initializer<S>(&mut self, initializer: S)
+    where
+        S: Into<Statement>,
+    {
+        self.initializer = Some(initializer.into());
+    }
+}
+
+impl From<VariableDeclSpec> for Statement {
+    fn from(value: VariableDeclSpec) -> Statement {
+        let mut out = Statement::new();
+
+        match value.kind {
+            DeclarationKind::Const => out.push("const "),
+            DeclarationKind::Let => out.push("let "),
+        }
+
+        out.push(Statement::from(value.pattern));
+
+        if let Some(initializer) = value.initializer {
+            out.push(" = ");
+            out.push(initializer);
+        }
+
+        out.push(";");
+        out
+    }
+}
+
+impl From<VariableDeclSpec> for Element {
+    fn from(value: VariableDeclSpec) -> Element {
+        Push(value.into())
+    }
+}
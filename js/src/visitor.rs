@@ -0,0 +1,123 @@
+use codeviz_common::{Visitor, VisitorMut};
+use super::*;
+
+/// Walk the children of a variable, like the entries of an object or the body of an arrow
+/// function.
+pub fn walk_variable<V>(visitor: &mut V, variable: &Variable)
+where
+    V: Visitor<Variable> + ?Sized,
+{
+    match *variable {
+        Variable::Statement(ref stmt) => {
+            visitor.visit_statement(stmt);
+        }
+        Variable::Object(ref object) => {
+            for entry in &object.entries {
+                match *entry {
+                    ObjectEntry::Property(ref key, ref value) => {
+                        if let ObjectKey::Computed(ref key) = *key {
+                            visitor.visit_statement(key);
+                        }
+
+                        visitor.visit_statement(value);
+                    }
+                    ObjectEntry::Spread(ref value) => {
+                        visitor.visit_statement(value);
+                    }
+                    ObjectEntry::Shorthand(_) => {}
+                }
+            }
+        }
+        Variable::Array(ref array) => {
+            for entry in &array.entries {
+                match *entry {
+                    ArrayEntry::Value(ref value) | ArrayEntry::Spread(ref value) => {
+                        visitor.visit_statement(value);
+                    }
+                }
+            }
+        }
+        Variable::Arrow(ref arrow) => {
+            for argument in &arrow.arguments {
+                visitor.visit_statement(argument);
+            }
+
+            match arrow.body {
+                ArrowBody::Expression(ref stmt) => visitor.visit_statement(stmt),
+                ArrowBody::Block(ref elements) => visitor.visit_elements(elements),
+            }
+        }
+        Variable::Template(ref parts) => {
+            for part in parts {
+                if let TemplatePart::Expression(ref stmt) = *part {
+                    visitor.visit_statement(stmt);
+                }
+            }
+        }
+        Variable::Literal(_) |
+        Variable::String(_) |
+        Variable::Name(_) |
+        Variable::Export(_) => {}
+    }
+}
+
+/// Walk the children of a variable, permitting them to be rewritten.
+///
+/// See `walk_variable`.
+pub fn walk_variable_mut<V>(visitor: &mut V, variable: &mut Variable)
+where
+    V: VisitorMut<Variable> + ?Sized,
+{
+    match *variable {
+        Variable::Statement(ref mut stmt) => {
+            visitor.visit_statement_mut(stmt);
+        }
+        Variable::Object(ref mut object) => {
+            for entry in &mut object.entries {
+                match *entry {
+                    ObjectEntry::Property(ref mut key, ref mut value) => {
+                        if let ObjectKey::Computed(ref mut key) = *key {
+                            visitor.visit_statement_mut(key);
+                        }
+
+                        visitor.visit_statement_mut(value);
+                    }
+                    ObjectEntry::Spread(ref mut value) => {
+                        visitor.visit_statement_mut(value);
+                    }
+                    ObjectEntry::Shorthand(_) => {}
+                }
+            }
+        }
+        Variable::Array(ref mut array) => {
+            for entry in &mut array.entries {
+                match *entry {
+                    ArrayEntry::Value(ref mut value) | ArrayEntry::Spread(ref mut value) => {
+                        visitor.visit_statement_mut(value);
+                    }
+                }
+            }
+        }
+        Variable::Arrow(ref mut arrow) => {
+            for argument in &mut arrow.arguments {
+                visitor.visit_statement_mut(argument);
+            }
+
+            match arrow.body {
+                ArrowBody::Expression(ref mut stmt) => visitor.visit_statement_mut(stmt),
+                ArrowBody::Block(ref mut elements) => visitor.visit_elements_mut(elements),
+            }
+        }
+        Variable::Template(ref mut parts) => {
+            for part in parts {
+                if let TemplatePart::Expression(ref mut stmt) = *part {
+                    visitor.visit_statement_mut(stmt);
+                }
+            }
+        }
+        Variable::Literal(_) |
+        Variable::String(_) |
+        Variable::Name(_) |
+        Variable::Export(_) => {}
+    }
+}
@@ -0,0 +1,171 @@
+use super::*;
+use codeviz_common::ElementFormat;
+
+/// Python type annotations.
+#[derive(Debug, Clone)]
+pub enum Type {
+    /// A named type, like `int` or an imported class.
+    Name(Name),
+    /// A generic type and its arguments, like `Dict[str, int]`.
+    Generic(Box<Type>, Vec<Type>),
+    /// An optional type, like `Optional[int]`.
+    Optional(Box<Type>),
+    /// A union type, like `int | str`.
+    Union(Vec<Type>),
+    /// A callable type, like `Callable[[int, str], bool]`.
+    Callable(Vec<Type>, Box<Type>),
+    /// The `None` type.
+    None,
+}
+
+impl Type {
+    pub fn name<N>(name: N) -> Type
+    where
+        N: Into<Name>,
+    {
+        Type::Name(name.into())
+    }
+
+    pub fn built_in(name: &str) -> Type {
+        Type::Name(Name::built_in(name).into())
+    }
+
+    pub fn generic<B, A>(base: B, arguments: Vec<A>) -> Type
+    where
+        B: Into<Type>,
+        A: Into<Type>,
+    {
+        let arguments = arguments.into_iter().map(Into::into).collect();
+        Type::Generic(Box::new(base.into()), arguments)
+    }
+
+    pub fn optional<T>(inner: T) -> Type
+    where
+        T: Into<Type>,
+    {
+        Type::Optional(Box::new(inner.into()))
+    }
+
+    pub fn union<T>(types: Vec<T>) -> Type
+    where
+        T: Into<Type>,
+    {
+        Type::Union(types.into_iter().map(Into::into).collect())
+    }
+
+    pub fn callable<A, R>(arguments: Vec<A>, returns: R) -> Type
+    where
+        A: Into<Type>,
+        R: Into<Type>,
+    {
+        let arguments = arguments.into_iter().map(Into::into).collect();
+        Type::Callable(arguments, Box::new(returns.into()))
+    }
+
+    pub fn format<E>(&self, out: &mut E, extra: &Extra) -> Result<()>
+    where
+        E: ElementFormat,
+    {
+        match *self {
+            Type::Name(ref name) => name.format(out, extra)?,
+            Type::Generic(ref base, ref arguments) => {
+                base.format(out, extra)?;
+                out.write_char('[')?;
+                format_joined(out, arguments, ", ", extra)?;
+                out.write_char(']')?;
+            }
+            Type::Optional(ref inner) => {
+                Name::from(typing("Optional")).format(out, extra)?;
+                out.write_char('[')?;
+                inner.format(out, extra)?;
+                out.write_char(']')?;
+            }
+            Type::Union(ref types) => format_joined(out, types, " | ", extra)?,
+            Type::Callable(ref arguments, ref returns) => {
+                Name::from(typing("Callable")).format(out, extra)?;
+                out.write_str("[[")?;
+                format_joined(out, arguments, ", ", extra)?;
+                out.write_str("], ")?;
+                returns.format(out, extra)?;
+                out.write_char(']')?;
+            }
+            Type::None => out.write_str("None")?,
+        }
+
+        Ok(())
+    }
+
+    /// Names which have to be imported to use this type.
+    pub fn imported_names(&self) -> Vec<ImportedName> {
+        let mut out = Vec::new();
+        self.imports(&mut out);
+        out
+    }
+}
+
+/// Name of a special form in the `typing` module.
+pub(crate) fn typing(name: &str) -> ImportedName {
+    Name::imported("typing", name)
+}
+
+fn format_joined<E>(out: &mut E, types: &[Type], separator: &str, extra: &Extra) -> Result<()>
+where
+    E: ElementFormat,
+{
+    let mut it = types.iter().peekable();
+
+    while let Some(ty) = it.next() {
+        ty.format(out, extra)?;
+
+        if it.peek().is_some() {
+            out.write_str(separator)?;
+        }
+    }
+
+    Ok(())
+}
+
+impl<'a, T> From<&'a T> for Type
+where
+    T: Into<Type> + Clone,
+{
+    fn from(value: &'a T) -> Type {
+        value.clone().into()
+    }
+}
+
+impl From<Name> for Type {
+    fn from(value: Name) -> Type {
+        Type::Name(value)
+    }
+}
+
+impl From<ImportedName> for Type {
+    fn from(value: ImportedName) -> Type {
+        Type::Name(value.into())
+    }
+}
+
+impl From<BuiltInName> for Type {
+    fn from(value: BuiltInName) -> Type {
+        Type::Name(value.into())
+    }
+}
+
+impl From<LocalName> for Type {
+    fn from(value: LocalName) -> Type {
+        Type::Name(value.into())
+    }
+}
+
+impl From<Type> for Variable {
+    fn from(value: Type) -> Variable {
+        Variable::Type(value)
+    }
+}
+
+impl ImportReceiver for Vec<ImportedName> {
+    fn receive(&mut self, name: &ImportedName) {
+        self.push(name.clone());
+    }
+}
@@ -0,0 +1,101 @@
+use super::*;
+
+/// How an argument can be passed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgumentKind {
+    /// An argument which can be passed by position or keyword.
+    Normal,
+    /// An argument which can only be passed by position, declared before `/`.
+    PositionalOnly,
+    /// An argument which can only be passed by keyword, declared after `*`.
+    KeywordOnly,
+    /// Collects remaining positional arguments, like `*args`.
+    VarArgs,
+    /// Collects remaining keyword arguments, like `**kwargs`.
+    KwArgs,
+}
+
+/// An argument of a function, like `name: str = "world"`.
+#[derive(Debug, Clone)]
+pub struct ArgumentSpec {
+    pub name: String,
+    pub annotation: Option<Type>,
+    pub default: Option<Statement>,
+    pub kind: ArgumentKind,
+}
+
+impl ArgumentSpec {
+    pub fn new(name: &str) -> ArgumentSpec {
+        ArgumentSpec {
+            name: name.to_owned(),
+            annotation: None,
+            default: None,
+            kind: ArgumentKind::Normal,
+        }
+    }
+
+    pub fn with_kind(name: &str, kind: ArgumentKind) -> ArgumentSpec {
+        ArgumentSpec {
+            kind: kind,
+            ..ArgumentSpec::new(name)
+        }
+    }
+
+    pub fn annotation<T>(&mut self, annotation: T)
+    where
+        T: Into<Type>,
+    {
+        self.annotation = Some(annotation.into());
+    }
+
+    pub fn default<S>(&mut self, default: S)
+    where
+        S: Into<Statement>,
+    {
+        self.default = Some(default.into());
+    }
+}
+
+impl<'a, T> From<&'a T> for ArgumentSpec
+where
+    T: Into<ArgumentSpec> + Clone,
+{
+    fn from(value: &'a T) -> ArgumentSpec {
+        value.clone().into()
+    }
+}
+
+impl<'a> From<&'a str> for ArgumentSpec {
+    fn from(value: &'a str) -> ArgumentSpec {
+        ArgumentSpec::new(value)
+    }
+}
+
+impl From<ArgumentSpec> for Statement {
+    fn from(value: ArgumentSpec) -> Statement {
+        let mut out = Statement::new();
+
+        match value.kind {
+            ArgumentKind::VarArgs => out.push("*"),
+            ArgumentKind::KwArgs => out.push("**"),
+            _ => {}
+        }
+
+        out.push(value.name);
+
+        if let Some(annotation) = value.annotation {
+            out.push(": ");
+            out.push(Variable::Annotation(annotation));
+
+            if let Some(default) = value.default {
+                out.push(" = ");
+                out.push(default);
+            }
+        } else if let Some(default) = value.default {
+            out.push("=");
+            out.push(default);
+        }
+
+        out
+    }
+}
@@ -1,11 +1,24 @@
 use super::*;
 
+/// How a method is bound to its class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MethodKind {
+    /// A method receiving the instance as `self`.
+    Instance,
+    /// A `@classmethod`, receiving the class as `cls`.
+    Class,
+    /// A `@staticmethod`, which receives neither.
+    Static,
+}
+
 #[derive(Debug, Clone)]
 pub struct ClassSpec {
     pub name: String,
     pub decorators: Vec<DecoratorSpec>,
     pub elements: Elements,
     pub extends: Vec<Name>,
+    /// Keywords passed to the class declaration, like `metaclass=Meta`.
+    pub keywords: Vec<(String, Statement)>,
 }
 
 impl ClassSpec {
@@ -15,6 +28,7 @@ impl ClassSpec {
             decorators: Vec::new(),
             elements: Elements::new(),
             extends: Vec::new(),
+            keywords: Vec::new(),
         }
     }
 
@@ -32,27 +46,67 @@ impl ClassSpec {
         self.elements.push(element);
     }
 
+    /// Push a method of the given kind, inserting its decorator and first argument.
+    pub fn push_method(&mut self, kind: MethodKind, mut method: MethodSpec) {
+        match kind {
+            MethodKind::Instance => {
+                method.arguments.insert(0, ArgumentSpec::new("self"));
+            }
+            MethodKind::Class => {
+                method.decorators.insert(0, Name::built_in("classmethod").into());
+                method.arguments.insert(0, ArgumentSpec::new("cls"));
+            }
+            MethodKind::Static => {
+                method.decorators.insert(0, Name::built_in("staticmethod").into());
+            }
+        }
+
+        self.elements.push(method);
+    }
+
+    /// Push a `@property`, and optionally its setter.
+    ///
+    /// The setter is renamed to the name of the property.
+    pub fn push_property(&mut self, mut getter: MethodSpec, setter: Option<MethodSpec>) {
+        let name = getter.name.clone();
+
+        getter.decorators.insert(0, Name::built_in("property").into());
+        getter.arguments.insert(0, ArgumentSpec::new("self"));
+        self.elements.push(getter);
+
+        if let Some(mut setter) = setter {
+            let mut decorator = DecoratorSpec::new(Name::local(&name));
+            decorator.push_attribute("setter");
+
+            setter.name = name;
+            setter.decorators.insert(0, decorator);
+            setter.arguments.insert(0, ArgumentSpec::new("self"));
+            self.elements.push(setter);
+        }
+    }
+
     pub fn extends<N>(&mut self, name: N)
     where
         N: Into<Name>,
     {
         self.extends.push(name.into());
     }
+
+    pub fn push_keyword<S>(&mut self, keyword: &str, statement: S)
+    where
+        S: Into<Statement>,
+    {
+        self.keywords.push((keyword.to_owned(), statement.into()));
+    }
 }
 
 impl From<ClassSpec> for Element {
     fn from(value: ClassSpec) -> Element {
-        let mut out = Elements::new();
-
-        for decorator in value.decorators {
-            out.push(decorator);
-        }
-
         let mut decl = Statement::new();
         decl.push("class ");
-        decl.push(value.name);
+        decl.push(value.name.clone());
 
-        if !value.extends.is_empty() {
+        if !value.extends.is_empty() || !value.keywords.is_empty() {
             decl.push("(");
 
             let mut extends = Statement::new();
@@ -61,20 +115,24 @@ impl From<ClassSpec> for Element {
                 extends.push(extend);
             }
 
+            for (keyword, argument) in value.keywords {
+                let mut s = Statement::new();
+                s.push(keyword);
+                s.push("=");
+                s.push(argument);
+                extends.push(s);
+            }
+
             decl.push(extends.join(", "));
             decl.push(")");
         }
 
-        decl.push(":");
-
-        out.push(decl);
-
-        if value.elements.is_empty() {
-            out.push_nested("pass");
-        } else {
-            out.push_nested(value.elements.join(Spacing));
-        }
-
-        out.into()
+        Definition {
+            kind: DefinitionKind::Class,
+            name: value.name,
+            decorators: value.decorators,
+            declaration: decl,
+            body: value.elements.join(Spacing),
+        }.into()
     }
 }
@@ -0,0 +1,60 @@
+use super::*;
+
+/// Build the argument list of a function, inserting the `/` and `*` separators required by
+/// positional-only and keyword-only arguments.
+pub fn arguments(arguments: Vec<ArgumentSpec>) -> Statement {
+    let positional_only = arguments
+        .iter()
+        .rposition(|a| a.kind == ArgumentKind::PositionalOnly);
+
+    let mut out = Statement::new();
+    // if keyword-only arguments have been separated, either by `*` or `*args`.
+    let mut separated = false;
+
+    for (index, argument) in arguments.into_iter().enumerate() {
+        match argument.kind {
+            ArgumentKind::VarArgs => separated = true,
+            ArgumentKind::KeywordOnly if !separated => {
+                out.push("*");
+                separated = true;
+            }
+            _ => {}
+        }
+
+        out.push(Statement::from(argument));
+
+        if Some(index) == positional_only {
+            out.push("/");
+        }
+    }
+
+    out.join(", ")
+}
+
+/// Build a block, like `if x:` followed by its indented body.
+///
+/// An empty body is filled with `pass`, since Python doesn't permit empty blocks.
+pub fn block(mut header: Statement, body: Elements) -> Element {
+    header.push(":");
+
+    let mut out: Vec<Element> = Vec::new();
+    out.push(header.into());
+
+    if body.is_empty() {
+        out.push(Nested(Box::new("pass".into())));
+    } else {
+        out.push(Nested(Box::new(body.into())));
+    }
+
+    Inner(out)
+}
+
+/// Build an element which fails to format, since the statement it replaces would be invalid.
+pub fn invalid(reason: &str) -> Element {
+    Push(Variable::Invalid(reason.to_owned()).into())
+}
+
+/// Build a keyword block without a header expression, like `else:`.
+pub fn keyword_block(keyword: &str, body: Elements) -> Element {
+    block(keyword.into(), body)
+}
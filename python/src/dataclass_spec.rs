@@ -0,0 +1,89 @@
+use super::*;
+
+/// Builds a class decorated with `@dataclass`.
+#[derive(Debug, Clone)]
+pub struct DataClassSpec {
+    pub name: String,
+    pub fields: Vec<FieldSpec>,
+    pub frozen: bool,
+    pub slots: bool,
+}
+
+impl DataClassSpec {
+    pub fn new(name: &str) -> DataClassSpec {
+        DataClassSpec {
+            name: name.to_owned(),
+            fields: Vec::new(),
+            frozen: false,
+            slots: false,
+        }
+    }
+
+    pub fn push_field<F>(&mut self, field: F)
+    where
+        F: Into<FieldSpec>,
+    {
+        self.fields.push(field.into());
+    }
+
+    /// Make instances immutable, with `frozen=True`.
+    pub fn frozen(&mut self) {
+        self.frozen = true;
+    }
+
+    /// Generate `__slots__` for the class, with `slots=True`.
+    pub fn slots(&mut self) {
+        self.slots = true;
+    }
+
+    pub fn build(self) -> ClassSpec {
+        let mut decorator = DecoratorSpec::new(Name::imported("dataclasses", "dataclass"));
+
+        if self.frozen {
+            decorator.push_keyword("frozen", "True");
+        }
+
+        if self.slots {
+            decorator.push_keyword("slots", "True");
+        }
+
+        let mut class = ClassSpec::new(&self.name);
+        class.push_decorator(decorator);
+
+        let mut fields = Elements::new();
+
+        for field in self.fields {
+            let value = match field.default_factory {
+                Some(ref factory) => {
+                    let mut value = Statement::new();
+                    value.push(Name::imported("dataclasses", "field"));
+                    value.push("(default_factory=");
+                    value.push(factory.clone());
+                    value.push(")");
+                    Some(value)
+                }
+                None => field.default.clone(),
+            };
+
+            fields.push(field.declaration(value));
+        }
+
+        if !fields.is_empty() {
+            class.push(fields);
+        }
+
+        class
+    }
+}
+
+impl From<DataClassSpec> for ClassSpec {
+    fn from(value: DataClassSpec) -> ClassSpec {
+        value.build()
+    }
+}
+
+impl From<DataClassSpec> for Element {
+    fn from(value: DataClassSpec) -> Element {
+        value.build().into()
+    }
+}
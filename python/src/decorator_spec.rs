@@ -1,9 +1,15 @@
 use super::*;
 
+/// A decorator, like `@app.route("/x", methods=["GET"])`.
 #[derive(Debug, Clone)]
 pub struct DecoratorSpec {
     pub name: Name,
+    /// Attributes accessed on the name, like `route` in `@app.route`.
+    pub attributes: Vec<String>,
     pub arguments: Vec<Statement>,
+    pub keywords: Vec<(String, Statement)>,
+    /// If the decorator is called even without arguments, like `@fixture()`.
+    pub call: bool,
 }
 
 impl DecoratorSpec {
@@ -13,16 +19,35 @@ impl DecoratorSpec {
     {
         DecoratorSpec {
             name: name.into(),
+            attributes: Vec::new(),
             arguments: Vec::new(),
+            keywords: Vec::new(),
+            call: false,
         }
     }
 
+    pub fn push_attribute(&mut self, attribute: &str) {
+        self.attributes.push(attribute.to_owned());
+    }
+
     pub fn push_argument<S>(&mut self, statement: S)
     where
         S: Into<Statement>,
     {
         self.arguments.push(statement.into());
     }
+
+    pub fn push_keyword<S>(&mut self, keyword: &str, statement: S)
+    where
+        S: Into<Statement>,
+    {
+        self.keywords.push((keyword.to_owned(), statement.into()));
+    }
+
+    /// Call the decorator, even if it has no arguments.
+    pub fn call(&mut self) {
+        self.call = true;
+    }
 }
 
 impl<'a, T> From<&'a T> for DecoratorSpec
@@ -53,6 +78,31 @@ impl From<DecoratorSpec> for Element {
         decl.push("@");
         decl.push(value.name);
 
+        for attribute in value.attributes {
+            decl.push(".");
+            decl.push(attribute);
+        }
+
+        if value.call || !value.arguments.is_empty() || !value.keywords.is_empty() {
+            let mut arguments = Statement::new();
+
+            for argument in value.arguments {
+                arguments.push(argument);
+            }
+
+            for (keyword, argument) in value.keywords {
+                let mut s = Statement::new();
+                s.push(keyword);
+                s.push("=");
+                s.push(argument);
+                arguments.push(s);
+            }
+
+            decl.push("(");
+            decl.push(arguments.join(", "));
+            decl.push(")");
+        }
+
         decl.into()
     }
 }
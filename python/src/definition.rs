@@ -0,0 +1,177 @@
+use super::*;
+
+/// What is being defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefinitionKind {
+    Class,
+    Function,
+}
+
+/// A class or function definition.
+///
+/// Definitions keep their declaration apart from their body, so that a file can be rendered as a
+/// stub where bodies are replaced by `...`.
+#[derive(Debug, Clone)]
+pub struct Definition {
+    pub kind: DefinitionKind,
+    pub name: String,
+    pub decorators: Vec<DecoratorSpec>,
+    /// The declaration without its trailing colon, like `def name(a: int) -> str`.
+    pub declaration: Statement,
+    pub body: Elements,
+}
+
+impl Definition {
+    /// If the name is private, like `_name`, but not special like `__init__`.
+    pub fn is_private(&self) -> bool {
+        self.name.starts_with('_') && !(self.name.starts_with("__") && self.name.ends_with("__"))
+    }
+
+    /// Build the stub of this definition, or nothing if it is private and those are dropped.
+    pub fn stub(&self, drop_private: bool) -> Option<Element> {
+        if drop_private && self.is_private() {
+            return None;
+        }
+
+        let mut out = Elements::new();
+
+        for decorator in &self.decorators {
+            out.push(decorator);
+        }
+
+        let body = match self.kind {
+            DefinitionKind::Class => stub_elements(&self.body, drop_private),
+            DefinitionKind::Function => Elements::new(),
+        };
+
+        if body.is_empty() {
+            let mut decl = self.declaration.clone();
+            decl.push(": ...");
+            out.push(decl);
+        } else {
+            out.push(block(self.declaration.clone(), body));
+        }
+
+        Some(out.into())
+    }
+}
+
+impl From<Definition> for Element {
+    fn from(value: Definition) -> Element {
+        Push(Variable::Definition(Box::new(value)).into())
+    }
+}
+
+impl From<Definition> for Elements {
+    fn from(value: Definition) -> Elements {
+        let mut out = Elements::new();
+
+        for decorator in value.decorators {
+            out.push(decorator);
+        }
+
+        out.push(block(value.declaration, value.body));
+        out
+    }
+}
+
+/// Replace the bodies of all definitions with `...`.
+///
+/// Compound statements, like `if` and `for`, are dropped since they only do something at
+/// runtime, and assignments are reduced to their target, like `NAME = ...`. Other statements
+/// are kept as they are.
+pub fn stub_elements(elements: &Elements, drop_private: bool) -> Elements {
+    Elements {
+        elements: stub_all(&elements.elements, drop_private),
+    }
+}
+
+fn stub_all(elements: &[Element], drop_private: bool) -> Vec<Element> {
+    let mut out: Vec<Element> = Vec::new();
+
+    for element in elements {
+        let element = match stub_element(element, drop_private) {
+            Some(element) => element,
+            None => continue,
+        };
+
+        // spacing around dropped definitions would otherwise pile up.
+        if let Spacing = element {
+            match out.last() {
+                None | Some(&Spacing) => continue,
+                _ => {}
+            }
+        }
+
+        out.push(element);
+    }
+
+    if let Some(&Spacing) = out.last() {
+        out.pop();
+    }
+
+    out
+}
+
+fn stub_element(element: &Element, drop_private: bool) -> Option<Element> {
+    match *element {
+        Push(ref statement) => match statement.parts.as_slice() {
+            [Variable::Definition(ref definition)] => definition.stub(drop_private),
+            _ => Some(Push(stub_statement(statement))),
+        },
+        // the body of a compound statement is the only thing that is nested.
+        Inner(ref elements) if elements.iter().any(|e| matches!(*e, Nested(_))) => None,
+        Inner(ref elements) => {
+            let elements = stub_all(elements, drop_private);
+
+            if elements.is_empty() {
+                return None;
+            }
+
+            Some(Inner(elements))
+        }
+        Nested(_) => None,
+        _ => Some(element.clone()),
+    }
+}
+
+/// Reduce an assignment to its target, like `NAME = ...` or `NAME: int = ...`.
+///
+/// Only literal parts of the statement are searched for the assignment, and statements which
+/// are not assignments are kept as they are.
+fn stub_statement(statement: &Statement) -> Statement {
+    let mut out = Statement::new();
+    let mut depth = 0usize;
+
+    for part in &statement.parts {
+        if let Variable::Literal(ref text) = *part {
+            let mut previous = None;
+            let mut chars = text.char_indices().peekable();
+
+            while let Some((index, c)) = chars.next() {
+                match c {
+                    '(' | '[' | '{' => depth += 1,
+                    ')' | ']' | '}' => depth = depth.saturating_sub(1),
+                    '=' if depth == 0 => {
+                        let next = chars.peek().map(|&(_, c)| c);
+                        let comparison = next == Some('=') ||
+                            previous.map(|p| "=!<>".contains(p)).unwrap_or(false);
+
+                        if !comparison {
+                            out.push(text[..index + 1].to_owned());
+                            out.push(" ...");
+                            return out;
+                        }
+                    }
+                    _ => {}
+                }
+
+                previous = Some(c);
+            }
+        }
+
+        out.push(part.clone());
+    }
+
+    statement.clone()
+}
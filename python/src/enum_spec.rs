@@ -0,0 +1,81 @@
+use super::*;
+
+/// Builds a subclass of `enum.Enum`.
+#[derive(Debug, Clone)]
+pub struct EnumSpec {
+    pub name: String,
+    pub base: Name,
+    /// Members and their values, where `None` uses `enum.auto()`.
+    pub members: Vec<(String, Option<Statement>)>,
+}
+
+impl EnumSpec {
+    pub fn new(name: &str) -> EnumSpec {
+        EnumSpec {
+            name: name.to_owned(),
+            base: Name::imported("enum", "Enum").into(),
+            members: Vec::new(),
+        }
+    }
+
+    /// Use another base class, like `enum.IntEnum`.
+    pub fn base<N>(&mut self, base: N)
+    where
+        N: Into<Name>,
+    {
+        self.base = base.into();
+    }
+
+    pub fn push_member<S>(&mut self, name: &str, value: S)
+    where
+        S: Into<Statement>,
+    {
+        self.members.push((name.to_owned(), Some(value.into())));
+    }
+
+    /// Push a member with an automatic value.
+    pub fn push_auto(&mut self, name: &str) {
+        self.members.push((name.to_owned(), None));
+    }
+
+    pub fn build(self) -> ClassSpec {
+        let mut class = ClassSpec::new(&self.name);
+        class.extends(self.base);
+
+        let mut members = Elements::new();
+
+        for (name, value) in self.members {
+            let mut member = Statement::new();
+            member.push(name);
+            member.push(" = ");
+
+            match value {
+                Some(value) => member.push(value),
+                None => {
+                    member.push(Name::imported("enum", "auto"));
+                    member.push("()");
+                }
+            }
+
+            members.push(member);
+        }
+
+        if !members.is_empty() {
+            class.push(members);
+        }
+
+        class
+    }
+}
+
+impl From<EnumSpec> for ClassSpec {
+    fn from(value: EnumSpec) -> ClassSpec {
+        value.build()
+    }
+}
+
+impl From<EnumSpec> for Element {
+    fn from(value: EnumSpec) -> Element {
+        value.build().into()
+    }
+}
@@ -0,0 +1,23 @@
+use std::collections::BTreeMap;
+use super::*;
+
+pub struct Extra {
+    /// How imported names are referenced, as decided by the file they are imported in.
+    pub names: BTreeMap<ImportedName, String>,
+}
+
+impl Extra {
+    pub fn new() -> Extra {
+        Extra { names: BTreeMap::new() }
+    }
+
+    pub fn with_names(names: BTreeMap<ImportedName, String>) -> Extra {
+        Extra { names: names }
+    }
+}
+
+impl Default for Extra {
+    fn default() -> Extra {
+        Extra::new()
+    }
+}
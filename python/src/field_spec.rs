@@ -0,0 +1,65 @@
+use super::*;
+
+/// An annotated field of a data model, like `name: str = "world"`.
+#[derive(Debug, Clone)]
+pub struct FieldSpec {
+    pub name: String,
+    pub annotation: Type,
+    pub default: Option<Statement>,
+    /// Factory producing the default value, like `list`.
+    ///
+    /// Only supported by dataclasses, where it renders as `field(default_factory=list)`.
+    pub default_factory: Option<Statement>,
+}
+
+impl FieldSpec {
+    pub fn new<T>(name: &str, annotation: T) -> FieldSpec
+    where
+        T: Into<Type>,
+    {
+        FieldSpec {
+            name: name.to_owned(),
+            annotation: annotation.into(),
+            default: None,
+            default_factory: None,
+        }
+    }
+
+    pub fn default<S>(&mut self, default: S)
+    where
+        S: Into<Statement>,
+    {
+        self.default = Some(default.into());
+    }
+
+    pub fn default_factory<S>(&mut self, factory: S)
+    where
+        S: Into<Statement>,
+    {
+        self.default_factory = Some(factory.into());
+    }
+
+    /// Build the declaration of this field, with the given value if any.
+    pub(crate) fn declaration(self, value: Option<Statement>) -> Statement {
+        let mut out = Statement::new();
+        out.push(self.name);
+        out.push(": ");
+        out.push(Variable::Annotation(self.annotation));
+
+        if let Some(value) = value {
+            out.push(" = ");
+            out.push(value);
+        }
+
+        out
+    }
+}
+
+impl<'a, T> From<&'a T> for FieldSpec
+where
+    T: Into<FieldSpec> + Clone,
+{
+    fn from(value: &'a T) -> FieldSpec {
+        value.clone().into()
+    }
+}
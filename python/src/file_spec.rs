@@ -1,15 +1,120 @@
-use codeviz_common::ElementFormatter;
-use std::collections::BTreeSet;
+use codeviz_common::{ElementFormatter, Visitor, walk_element};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use super::*;
 
+/// Maximum length of a `from module import ...` line before it is wrapped in parentheses.
+const MAX_IMPORT_WIDTH: usize = 79;
+/// Maximum length of the `__all__` declaration before it is split into one name per line.
+const MAX_ALL_WIDTH: usize = 79;
+
 #[derive(Debug, Clone)]
 pub struct FileSpec {
     pub elements: Elements,
+    pub import_style: ImportStyle,
+    /// Prefixes of modules which are sorted into the first-party section.
+    pub first_party: Vec<String>,
+    /// Features imported from `__future__`.
+    pub futures: BTreeSet<String>,
+    pub type_checking: bool,
+    /// Interpreter line, like `/usr/bin/env python3`.
+    pub shebang: Option<String>,
+    /// Source encoding declared in the coding line, like `utf-8`.
+    pub encoding: Option<String>,
+    pub docstring: Option<String>,
+    /// Public names exported in addition to the top-level classes and functions.
+    pub exports: Vec<String>,
+    /// Declare `__all__` from the exported names.
+    pub all: bool,
+    /// Render the file as a `.pyi` stub.
+    pub stub: bool,
+    /// Drop private definitions when rendering a stub.
+    pub drop_private: bool,
 }
 
 impl FileSpec {
     pub fn new() -> FileSpec {
-        FileSpec { elements: Elements::new() }
+        FileSpec {
+            elements: Elements::new(),
+            import_style: ImportStyle::default(),
+            first_party: Vec::new(),
+            futures: BTreeSet::new(),
+            type_checking: false,
+            shebang: None,
+            encoding: None,
+            docstring: None,
+            exports: Vec::new(),
+            all: false,
+            stub: false,
+            drop_private: false,
+        }
+    }
+
+    /// Set the style used for names which don't specify how they are imported.
+    pub fn import_style(&mut self, import_style: ImportStyle) {
+        self.import_style = import_style;
+    }
+
+    /// Sort modules starting with the given prefix into the first-party section.
+    pub fn push_first_party(&mut self, prefix: &str) {
+        self.first_party.push(prefix.to_owned());
+    }
+
+    /// Import a feature from `__future__`, like `annotations`.
+    pub fn push_future(&mut self, feature: &str) {
+        self.futures.insert(feature.to_owned());
+    }
+
+    /// Guard imports of names which are only used in annotations with `if TYPE_CHECKING:`.
+    ///
+    /// This imports `annotations` from `__future__` if any imports are guarded, so that
+    /// annotations are not evaluated at runtime.
+    pub fn type_checking(&mut self) {
+        self.type_checking = true;
+    }
+
+    /// Start the file with `#!<interpreter>`.
+    pub fn shebang(&mut self, interpreter: &str) {
+        self.shebang = Some(interpreter.to_owned());
+    }
+
+    /// Declare the source encoding with a `# -*- coding: <encoding> -*-` line.
+    pub fn encoding(&mut self, encoding: &str) {
+        self.encoding = Some(encoding.to_owned());
+    }
+
+    pub fn docstring(&mut self, docstring: &str) {
+        self.docstring = Some(docstring.to_owned());
+    }
+
+    /// Declare `__all__` with the public top-level classes and functions of this file.
+    pub fn all(&mut self) {
+        self.all = true;
+    }
+
+    /// Render the file as a `.pyi` stub, where the bodies of all classes and functions are
+    /// replaced by `...`.
+    ///
+    /// Values of assignments are replaced by `...` as well, and other compound statements, like
+    /// `if __name__ == "__main__":`, are dropped.
+    ///
+    /// Imports which are only used in bodies are dropped, and imports are never guarded by
+    /// `if TYPE_CHECKING:` since stubs are not evaluated at runtime.
+    pub fn stub(&mut self) {
+        self.stub = true;
+    }
+
+    /// Drop private classes and functions, like `_helper`, when rendering a stub.
+    pub fn drop_private(&mut self) {
+        self.drop_private = true;
+    }
+
+    /// Add a name to `__all__`, like a module-level constant, unless it is private.
+    pub fn push_export(&mut self, name: &str) {
+        if name.starts_with('_') || self.exports.iter().any(|e| e == name) {
+            return;
+        }
+
+        self.exports.push(name.to_owned());
     }
 
     pub fn push<E>(&mut self, element: E)
@@ -19,53 +124,263 @@ impl FileSpec {
         self.elements.push(element);
     }
 
-    fn imports(&self) -> Option<Elements> {
-        let mut imports = BTreeSet::new();
+    /// Build the lines preceding everything else in the file.
+    fn header(&self) -> Option<Elements> {
+        let mut header = Elements::new();
 
-        self.elements.imports(&mut imports);
+        if let Some(ref shebang) = self.shebang {
+            header.push(format!("#!{}", shebang).as_str());
+        }
 
-        let modules: BTreeSet<(String, Option<String>)> = imports
-            .into_iter()
-            .map(|imported| (imported.module, imported.alias))
-            .collect();
+        if let Some(ref encoding) = self.encoding {
+            header.push(format!("# -*- coding: {} -*-", encoding).as_str());
+        }
+
+        if let Some(ref docstring) = self.docstring {
+            header.push(Statement::from(Variable::Docstring(docstring.clone())));
+        }
+
+        if header.is_empty() {
+            return None;
+        }
 
-        if modules.is_empty() {
+        Some(header)
+    }
+
+    /// Build the `__all__` declaration.
+    fn all_names(&self) -> Option<Elements> {
+        if !self.all {
             return None;
         }
 
+        let mut definitions = TopLevelNames(Vec::new());
+        definitions.visit_elements(&self.elements);
+
+        let mut exports: Vec<&str> = Vec::new();
+
+        for name in definitions.0.iter().chain(self.exports.iter()) {
+            if !name.starts_with('_') && !exports.contains(&name.as_str()) {
+                exports.push(name);
+            }
+        }
+
+        // exported names are identifiers, so they never need escaping.
+        let names: Vec<String> = exports.iter().map(|e| format!("\"{}\"", e)).collect();
+
+        let mut out = Elements::new();
+        let line = format!("__all__ = [{}]", names.join(", "));
+
+        if line.len() <= MAX_ALL_WIDTH {
+            out.push(line.as_str());
+            return Some(out);
+        }
+
+        let mut entries = Elements::new();
+
+        for name in names {
+            entries.push(format!("{},", name).as_str());
+        }
+
+        out.push("__all__ = [");
+        out.push_nested(entries);
+        out.push("]");
+        Some(out)
+    }
+
+    /// Build the imports of this file, and how each imported name should be referenced.
+    ///
+    /// Imports from `__future__` are returned separately, since they must precede `__all__`.
+    fn imports(
+        &self,
+        elements: &Elements,
+    ) -> (Option<Elements>, Option<Elements>, BTreeMap<ImportedName, String>) {
+        let mut imports = FileImports::default();
+
+        elements.imports(&mut imports);
+
+        let mut runtime = imports.runtime;
+        let mut guarded = BTreeSet::new();
+        let mut futures = self.futures.clone();
+
+        if self.type_checking && !self.stub {
+            guarded.extend(imports.annotations.difference(&runtime).cloned());
+
+            if !guarded.is_empty() {
+                runtime.insert(typing("TYPE_CHECKING"));
+                futures.insert(String::from("annotations"));
+            }
+        } else {
+            runtime.extend(imports.annotations);
+        }
+
+        let all: Vec<&ImportedName> = runtime.iter().chain(guarded.iter()).collect();
+        let (resolved, names) = self.resolve(&all);
+
+        let mut sections: BTreeMap<ImportSection, ImportBlock> = BTreeMap::new();
+        let mut guarded_sections: BTreeMap<ImportSection, ImportBlock> = BTreeMap::new();
+
+        for (imported, import) in all.iter().zip(resolved) {
+            let section = ImportSection::classify(import.module(), &self.first_party);
+
+            if guarded.contains(*imported) {
+                guarded_sections.entry(section).or_default().push(import);
+            } else {
+                sections.entry(section).or_default().push(import);
+            }
+        }
+
+        // imports which are needed at runtime don't have to be guarded.
+        for (section, block) in &sections {
+            if let Some(guarded) = guarded_sections.get_mut(section) {
+                guarded.remove_all(block);
+            }
+        }
+
+        guarded_sections.retain(|_, block| !block.is_empty());
+
+        let mut future = sections.remove(&ImportSection::Future).unwrap_or_default();
+        let mut blocks: Vec<Elements> = sections.into_values().map(|b| b.elements()).collect();
+
+        if !guarded_sections.is_empty() {
+            let mut guarded = Elements::new();
+
+            for b in guarded_sections.into_values() {
+                guarded.push(b.elements());
+            }
+
+            let mut header = Statement::new();
+            header.push("if ");
+            header.push(typing("TYPE_CHECKING"));
+
+            let mut guard = Elements::new();
+            guard.push(block(header, guarded.join(Spacing)));
+            blocks.push(guard);
+        }
+
+        for feature in futures {
+            future.push(Import::From(String::from("__future__"), feature, None));
+        }
+
+        let future = if future.is_empty() {
+            None
+        } else {
+            Some(future.elements())
+        };
+
+        if blocks.is_empty() {
+            return (future, None, names);
+        }
+
         let mut elements = Elements::new();
 
-        for (module, alias) in modules {
-            let mut s = Statement::new();
+        for b in blocks {
+            elements.push(b);
+        }
 
-            s.push("import ");
-            s.push(&module);
+        (future, Some(elements.join(Spacing)), names)
+    }
 
-            if let Some(ref alias) = alias {
-                s.push(" as ");
-                s.push(alias);
+    /// Decide how each imported name is imported and referenced.
+    fn resolve(&self, imports: &[&ImportedName]) -> (Vec<Import>, BTreeMap<ImportedName, String>) {
+        let style = |imported: &ImportedName| {
+            if imported.module == "__future__" {
+                return ImportStyle::From;
             }
 
-            elements.push(s);
+            imported.style.unwrap_or(self.import_style)
+        };
+
+        // names bound by `import module`.
+        let bound: BTreeSet<String> = imports
+            .iter()
+            .filter(|imported| style(imported) == ImportStyle::Module)
+            .map(|imported| match imported.alias {
+                Some(ref alias) => alias.clone(),
+                None => imported.module.split('.').next().unwrap_or(&imported.module).to_owned(),
+            })
+            .collect();
+
+        // what each local name from a `from` import would be bound to.
+        let mut locals: HashMap<&str, BTreeSet<(&str, &str)>> = HashMap::new();
+
+        for imported in imports {
+            if style(imported) == ImportStyle::From {
+                let local = imported.alias.as_ref().unwrap_or(&imported.name);
+
+                locals.entry(local.as_str()).or_default().insert((
+                    imported.module.as_str(),
+                    imported.name.as_str(),
+                ));
+            }
         }
 
-        Some(elements)
+        let mut resolved = Vec::new();
+        let mut names = BTreeMap::new();
+
+        for imported in imports {
+            if style(imported) == ImportStyle::Module {
+                resolved.push(Import::Module(imported.module.clone(), imported.alias.clone()));
+                continue;
+            }
+
+            let local = imported.alias.as_ref().unwrap_or(&imported.name);
+
+            // fall back to qualified access if the local name is ambiguous.
+            if locals[local.as_str()].len() > 1 || bound.contains(local) {
+                resolved.push(Import::Module(imported.module.clone(), None));
+                names.insert(
+                    (*imported).clone(),
+                    format!("{}.{}", imported.module, imported.name),
+                );
+                continue;
+            }
+
+            resolved.push(Import::From(
+                imported.module.clone(),
+                imported.name.clone(),
+                imported.alias.clone(),
+            ));
+
+            names.insert((*imported).clone(), local.clone());
+        }
+
+        (resolved, names)
     }
 
     pub fn format<W>(&self, out: &mut W) -> Result<()>
     where
         W: ::std::fmt::Write,
     {
+        let body = if self.stub {
+            stub_elements(&self.elements, self.drop_private)
+        } else {
+            self.elements.clone()
+        };
+
         let mut elements = Elements::new();
 
-        if let Some(imports) = self.imports() {
+        let (future, imports, names) = self.imports(&body);
+
+        if let Some(header) = self.header() {
+            elements.push(header);
+        }
+
+        if let Some(future) = future {
+            elements.push(future);
+        }
+
+        if let Some(all) = self.all_names() {
+            elements.push(all);
+        }
+
+        if let Some(imports) = imports {
             elements.push(imports);
         }
 
-        elements.push(self.elements.clone().join(Spacing));
+        elements.push(body.join(Spacing));
 
         let elements: Element = elements.clone().join(Spacing).into();
-        let mut extra = ();
+        let mut extra = Extra::with_names(names);
 
         elements.format(&mut ElementFormatter::new(out), &mut extra)?;
         out.write_char('\n')?;
@@ -74,6 +389,156 @@ impl FileSpec {
     }
 }
 
+/// Collects the names of top-level classes and functions.
+struct TopLevelNames(Vec<String>);
+
+impl Visitor<Variable> for TopLevelNames {
+    fn visit_element(&mut self, element: &Element) {
+        // definitions in nested elements are not bound at the top level.
+        if let Nested(_) = *element {
+            return;
+        }
+
+        walk_element(self, element);
+    }
+
+    fn visit_variable(&mut self, variable: &Variable) {
+        if let Variable::Definition(ref definition) = *variable {
+            self.0.push(definition.name.clone());
+        }
+    }
+}
+
+/// Names imported by a file, split by how they are referenced.
+#[derive(Debug, Default)]
+struct FileImports {
+    runtime: BTreeSet<ImportedName>,
+    annotations: BTreeSet<ImportedName>,
+}
+
+impl ImportReceiver for FileImports {
+    fn receive(&mut self, name: &ImportedName) {
+        self.runtime.insert(name.clone());
+    }
+
+    fn receive_annotation(&mut self, name: &ImportedName) {
+        self.annotations.insert(name.clone());
+    }
+}
+
+/// A single import.
+#[derive(Debug, Clone)]
+enum Import {
+    /// `import module`, or `import module as alias`.
+    Module(String, Option<String>),
+    /// `from module import name`, or `from module import name as alias`.
+    From(String, String, Option<String>),
+}
+
+impl Import {
+    fn module(&self) -> &str {
+        match *self {
+            Import::Module(ref module, _) | Import::From(ref module, _, _) => module,
+        }
+    }
+}
+
+/// Imports which are rendered together.
+#[derive(Debug, Default)]
+struct ImportBlock {
+    modules: BTreeSet<(String, Option<String>)>,
+    from: BTreeMap<String, BTreeSet<(String, Option<String>)>>,
+}
+
+impl ImportBlock {
+    fn push(&mut self, import: Import) {
+        match import {
+            Import::Module(module, alias) => {
+                self.modules.insert((module, alias));
+            }
+            Import::From(module, name, alias) => {
+                self.from.entry(module).or_default().insert((name, alias));
+            }
+        }
+    }
+
+    /// Remove all imports which are part of another block.
+    fn remove_all(&mut self, other: &ImportBlock) {
+        for module in &other.modules {
+            self.modules.remove(module);
+        }
+
+        for (module, names) in &other.from {
+            if let Some(existing) = self.from.get_mut(module) {
+                for name in names {
+                    existing.remove(name);
+                }
+            }
+        }
+
+        self.from.retain(|_, names| !names.is_empty());
+    }
+
+    fn is_empty(&self) -> bool {
+        self.modules.is_empty() && self.from.is_empty()
+    }
+
+    /// Build the import statements of this block, with `import` before `from` statements.
+    fn elements(self) -> Elements {
+        let mut elements = Elements::new();
+
+        for (module, alias) in self.modules {
+            let mut s = Statement::new();
+
+            s.push("import ");
+            s.push(&module);
+
+            if let Some(ref alias) = alias {
+                s.push(" as ");
+                s.push(alias);
+            }
+
+            elements.push(s);
+        }
+
+        for (module, imported) in self.from {
+            elements.push(from_import(&module, imported));
+        }
+
+        elements
+    }
+}
+
+/// Build a `from module import ...` statement, which is wrapped in parentheses if too long.
+fn from_import(module: &str, imported: BTreeSet<(String, Option<String>)>) -> Elements {
+    let imported: Vec<String> = imported
+        .into_iter()
+        .map(|(name, alias)| match alias {
+            Some(alias) => format!("{} as {}", name, alias),
+            None => name,
+        })
+        .collect();
+
+    let mut out = Elements::new();
+    let line = format!("from {} import {}", module, imported.join(", "));
+
+    if line.len() <= MAX_IMPORT_WIDTH {
+        out.push(line.as_str());
+        return out;
+    }
+
+    let mut names = Elements::new();
+
+    for name in imported {
+        names.push(format!("{},", name).as_str());
+    }
+
+    out.push(format!("from {} import (", module).as_str());
+    out.push_nested(names);
+    out.push(")");
+    out
+}
+
 impl ImportReceiver for BTreeSet<ImportedName> {
     fn receive(&mut self, name: &ImportedName) {
         self.insert(name.clone());
@@ -0,0 +1,65 @@
+use super::*;
+
+/// A `for` loop, with an optional `else` branch.
+#[derive(Debug, Clone)]
+pub struct ForSpec {
+    pub target: Statement,
+    pub iterable: Statement,
+    pub elements: Elements,
+    pub else_body: Option<Elements>,
+    pub is_async: bool,
+}
+
+impl ForSpec {
+    pub fn new<T, I>(target: T, iterable: I) -> ForSpec
+    where
+        T: Into<Statement>,
+        I: Into<Statement>,
+    {
+        ForSpec {
+            target: target.into(),
+            iterable: iterable.into(),
+            elements: Elements::new(),
+            else_body: None,
+            is_async: false,
+        }
+    }
+
+    /// Loop with `async for`.
+    pub fn asynchronous(&mut self) {
+        self.is_async = true;
+    }
+
+    pub fn body(&mut self) -> &mut Elements {
+        &mut self.elements
+    }
+
+    /// The body of the `else` branch, which runs if the loop wasn't broken out of.
+    pub fn otherwise(&mut self) -> &mut Elements {
+        self.else_body.get_or_insert_with(Elements::new)
+    }
+}
+
+impl From<ForSpec> for Element {
+    fn from(value: ForSpec) -> Element {
+        let mut header = Statement::new();
+
+        if value.is_async {
+            header.push("async ");
+        }
+
+        header.push("for ");
+        header.push(value.target);
+        header.push(" in ");
+        header.push(value.iterable);
+
+        let mut out = Elements::new();
+        out.push(block(header, value.elements));
+
+        if let Some(body) = value.else_body {
+            out.push(keyword_block("else", body));
+        }
+
+        out.into()
+    }
+}
@@ -0,0 +1,100 @@
+use super::*;
+
+/// A function, either at module level or nested in the body of another function.
+#[derive(Debug, Clone)]
+pub struct FunctionSpec {
+    pub name: String,
+    pub decorators: Vec<DecoratorSpec>,
+    pub arguments: Vec<ArgumentSpec>,
+    pub returns: Option<Type>,
+    pub elements: Elements,
+    pub is_async: bool,
+}
+
+impl FunctionSpec {
+    pub fn new(name: &str) -> FunctionSpec {
+        FunctionSpec {
+            name: name.to_owned(),
+            decorators: Vec::new(),
+            arguments: Vec::new(),
+            returns: None,
+            elements: Elements::new(),
+            is_async: false,
+        }
+    }
+
+    /// Declare the function with `async def`.
+    pub fn asynchronous(&mut self) {
+        self.is_async = true;
+    }
+
+    pub fn push_decorator<D>(&mut self, decorator: D)
+    where
+        D: Into<DecoratorSpec>,
+    {
+        self.decorators.push(decorator.into());
+    }
+
+    pub fn push_argument<A>(&mut self, argument: A)
+    where
+        A: Into<ArgumentSpec>,
+    {
+        self.arguments.push(argument.into());
+    }
+
+    pub fn returns<T>(&mut self, returns: T)
+    where
+        T: Into<Type>,
+    {
+        self.returns = Some(returns.into());
+    }
+
+    pub fn push<E>(&mut self, element: E)
+    where
+        E: Into<Element>,
+    {
+        self.elements.push(element);
+    }
+}
+
+impl From<MethodSpec> for FunctionSpec {
+    fn from(value: MethodSpec) -> FunctionSpec {
+        FunctionSpec {
+            name: value.name,
+            decorators: value.decorators,
+            arguments: value.arguments,
+            returns: value.returns,
+            elements: value.elements,
+            is_async: value.is_async,
+        }
+    }
+}
+
+impl From<FunctionSpec> for Element {
+    fn from(value: FunctionSpec) -> Element {
+        let mut decl = Statement::new();
+
+        if value.is_async {
+            decl.push("async ");
+        }
+
+        decl.push("def ");
+        decl.push(value.name.clone());
+        decl.push("(");
+        decl.push(arguments(value.arguments));
+        decl.push(")");
+
+        if let Some(returns) = value.returns {
+            decl.push(" -> ");
+            decl.push(Variable::Annotation(returns));
+        }
+
+        Definition {
+            kind: DefinitionKind::Function,
+            name: value.name,
+            decorators: value.decorators,
+            declaration: decl,
+            body: value.elements,
+        }.into()
+    }
+}
@@ -0,0 +1,59 @@
+use super::*;
+
+/// An `if` statement, with optional `elif` and `else` branches.
+#[derive(Debug, Clone)]
+pub struct IfSpec {
+    pub branches: Vec<(Statement, Elements)>,
+    pub else_body: Option<Elements>,
+}
+
+impl IfSpec {
+    pub fn new<S>(condition: S) -> IfSpec
+    where
+        S: Into<Statement>,
+    {
+        IfSpec {
+            branches: vec![(condition.into(), Elements::new())],
+            else_body: None,
+        }
+    }
+
+    /// The body of the last branch.
+    pub fn body(&mut self) -> &mut Elements {
+        let last = self.branches.len() - 1;
+        &mut self.branches[last].1
+    }
+
+    /// Add an `elif` branch, and return its body.
+    pub fn elif<S>(&mut self, condition: S) -> &mut Elements
+    where
+        S: Into<Statement>,
+    {
+        self.branches.push((condition.into(), Elements::new()));
+        self.body()
+    }
+
+    /// The body of the `else` branch.
+    pub fn otherwise(&mut self) -> &mut Elements {
+        self.else_body.get_or_insert_with(Elements::new)
+    }
+}
+
+impl From<IfSpec> for Element {
+    fn from(value: IfSpec) -> Element {
+        let mut out = Elements::new();
+
+        for (index, (condition, body)) in value.branches.into_iter().enumerate() {
+            let mut header = Statement::new();
+            header.push(if index == 0 { "if " } else { "elif " });
+            header.push(condition);
+            out.push(block(header, body));
+        }
+
+        if let Some(body) = value.else_body {
+            out.push(keyword_block("else", body));
+        }
+
+        out.into()
+    }
+}
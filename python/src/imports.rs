@@ -1,8 +1,14 @@
+use codeviz_common::Walk;
 use super::*;
 
 pub trait ImportReceiver {
     fn receive(&mut self, name: &ImportedName);
 
+    /// Receive a name which is only referenced in an annotation.
+    fn receive_annotation(&mut self, name: &ImportedName) {
+        self.receive(name);
+    }
+
     fn import_all<T>(&mut self, sources: &Vec<T>)
     where
         T: Imports,
@@ -14,6 +20,18 @@ pub trait ImportReceiver {
     }
 }
 
+/// Receiver which forwards every name as one which is only referenced in an annotation.
+struct Annotations<'a, I: 'a>(&'a mut I);
+
+impl<'a, I> ImportReceiver for Annotations<'a, I>
+where
+    I: ImportReceiver,
+{
+    fn receive(&mut self, name: &ImportedName) {
+        self.0.receive_annotation(name);
+    }
+}
+
 pub trait Imports {
     fn imports<I>(&self, receiver: &mut I)
     where
@@ -32,6 +50,33 @@ impl Imports for Name {
     }
 }
 
+impl Imports for Type {
+    fn imports<I>(&self, receiver: &mut I)
+    where
+        I: ImportReceiver,
+    {
+        match *self {
+            Type::Name(Name::Imported(ref imported)) => receiver.receive(imported),
+            Type::Name(_) => {}
+            Type::Generic(ref base, ref arguments) => {
+                base.imports(receiver);
+                receiver.import_all(arguments);
+            }
+            Type::Optional(ref inner) => {
+                receiver.receive(&typing("Optional"));
+                inner.imports(receiver);
+            }
+            Type::Union(ref types) => receiver.import_all(types),
+            Type::Callable(ref arguments, ref returns) => {
+                receiver.receive(&typing("Callable"));
+                receiver.import_all(arguments);
+                returns.imports(receiver);
+            }
+            Type::None => {}
+        }
+    }
+}
+
 impl Imports for Variable {
     fn imports<I>(&self, receiver: &mut I)
     where
@@ -44,6 +89,22 @@ impl Imports for Variable {
             Variable::Name(ref name) => {
                 name.imports(receiver);
             }
+            Variable::Type(ref ty) => {
+                ty.imports(receiver);
+            }
+            Variable::Annotation(ref ty) => {
+                ty.imports(&mut Annotations(receiver));
+            }
+            Variable::FString(ref parts) => {
+                for part in parts {
+                    if let FStringPart::Expression(ref stmt) = *part {
+                        stmt.imports(receiver);
+                    }
+                }
+            }
+            Variable::Definition(ref definition) => {
+                definition.imports(receiver);
+            }
             _ => {}
         }
     }
@@ -54,7 +115,7 @@ impl Imports for Statement {
     where
         I: ImportReceiver,
     {
-        receiver.import_all(&self.parts);
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
     }
 }
 
@@ -63,18 +124,30 @@ impl Imports for Element {
     where
         I: ImportReceiver,
     {
-        match *self {
-            Push(ref statement) => {
-                statement.imports(receiver);
-            }
-            Inner(ref elements) => {
-                receiver.import_all(elements);
-            }
-            Nested(ref element) => {
-                element.imports(receiver);
-            }
-            _ => {}
-        };
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
+    }
+}
+
+impl Imports for Elements {
+    fn imports<I>(&self, receiver: &mut I)
+    where
+        I: ImportReceiver,
+    {
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
+    }
+}
+
+impl Imports for DecoratorSpec {
+    fn imports<I>(&self, receiver: &mut I)
+    where
+        I: ImportReceiver,
+    {
+        self.name.imports(receiver);
+        receiver.import_all(&self.arguments);
+
+        for (_, argument) in &self.keywords {
+            argument.imports(receiver);
+        }
     }
 }
 
@@ -83,15 +156,24 @@ impl Imports for ClassSpec {
     where
         I: ImportReceiver,
     {
+        receiver.import_all(&self.decorators);
+        receiver.import_all(&self.extends);
+
+        for (_, argument) in &self.keywords {
+            argument.imports(receiver);
+        }
+
         self.elements.imports(receiver);
     }
 }
 
-impl Imports for Elements {
+impl Imports for Definition {
     fn imports<I>(&self, receiver: &mut I)
     where
         I: ImportReceiver,
     {
-        receiver.import_all(&self.elements);
+        receiver.import_all(&self.decorators);
+        self.declaration.imports(receiver);
+        self.body.imports(receiver);
     }
 }
@@ -1,21 +1,59 @@
 extern crate codeviz_common;
 
+mod _type;
+mod argument_spec;
 mod class_spec;
+mod common;
+mod dataclass_spec;
 mod decorator_spec;
+mod definition;
+mod enum_spec;
+mod extra;
+mod field_spec;
 mod file_spec;
+mod for_spec;
+mod function_spec;
+mod if_spec;
+mod import_section;
 mod imports;
+mod match_spec;
 mod method_spec;
 mod name;
+mod named_tuple_spec;
+mod try_spec;
+mod typed_dict_spec;
 mod variable;
+mod visitor;
+mod while_spec;
+mod with_spec;
 
 pub use codeviz_common::Element::*;
+pub use self::_type::*;
+pub use self::argument_spec::*;
 pub use self::class_spec::*;
+pub use self::dataclass_spec::*;
 pub use self::decorator_spec::*;
+pub use self::definition::*;
+pub use self::enum_spec::*;
+pub use self::extra::*;
+pub use self::field_spec::*;
 pub use self::file_spec::*;
+pub use self::for_spec::*;
+pub use self::function_spec::*;
+pub use self::if_spec::*;
+pub use self::import_section::*;
 pub use self::imports::*;
+pub use self::match_spec::*;
 pub use self::method_spec::*;
 pub use self::name::*;
+pub use self::named_tuple_spec::*;
+pub use self::try_spec::*;
+pub use self::typed_dict_spec::*;
 pub use self::variable::*;
+pub use self::visitor::*;
+pub use self::while_spec::*;
+pub use self::with_spec::*;
+pub(crate) use self::common::*;
 pub(crate) use self::errors::*;
 
 pub mod errors {
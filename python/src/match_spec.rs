@@ -0,0 +1,96 @@
+use super::*;
+
+const NO_CASES: &str = "`match` requires a `case` clause";
+
+/// A `case` clause of a `match` statement.
+#[derive(Debug, Clone)]
+pub struct CaseSpec {
+    pub pattern: Statement,
+    pub guard: Option<Statement>,
+    pub elements: Elements,
+}
+
+/// A `match` statement.
+#[derive(Debug, Clone)]
+pub struct MatchSpec {
+    pub subject: Statement,
+    pub cases: Vec<CaseSpec>,
+}
+
+impl MatchSpec {
+    pub fn new<S>(subject: S) -> MatchSpec
+    where
+        S: Into<Statement>,
+    {
+        MatchSpec {
+            subject: subject.into(),
+            cases: Vec::new(),
+        }
+    }
+
+    /// Add a `case` clause, and return its body.
+    pub fn case<S>(&mut self, pattern: S) -> &mut Elements
+    where
+        S: Into<Statement>,
+    {
+        self.push_case(pattern.into(), None)
+    }
+
+    /// Add a `case` clause with a guard, like `case x if x > 0:`, and return its body.
+    pub fn case_guarded<S, G>(&mut self, pattern: S, guard: G) -> &mut Elements
+    where
+        S: Into<Statement>,
+        G: Into<Statement>,
+    {
+        self.push_case(pattern.into(), Some(guard.into()))
+    }
+
+    fn push_case(&mut self, pattern: Statement, guard: Option<Statement>) -> &mut Elements {
+        self.cases.push(CaseSpec {
+            pattern: pattern,
+            guard: guard,
+            elements: Elements::new(),
+        });
+
+        let last = self.cases.len() - 1;
+        &mut self.cases[last].elements
+    }
+
+    /// Check that this is a valid `match` statement.
+    pub fn validate(&self) -> Result<()> {
+        if self.cases.is_empty() {
+            return Err(ErrorKind::InvalidStatement(NO_CASES.to_owned()).into());
+        }
+
+        Ok(())
+    }
+}
+
+impl From<MatchSpec> for Element {
+    fn from(value: MatchSpec) -> Element {
+        if value.cases.is_empty() {
+            return invalid(NO_CASES);
+        }
+
+        let mut cases = Elements::new();
+
+        for case in value.cases {
+            let mut header = Statement::new();
+            header.push("case ");
+            header.push(case.pattern);
+
+            if let Some(guard) = case.guard {
+                header.push(" if ");
+                header.push(guard);
+            }
+
+            cases.push(block(header, case.elements));
+        }
+
+        let mut header = Statement::new();
+        header.push("match ");
+        header.push(value.subject);
+
+        block(header, cases)
+    }
+}
@@ -4,8 +4,10 @@ use super::*;
 pub struct MethodSpec {
     pub name: String,
     pub decorators: Vec<DecoratorSpec>,
-    pub arguments: Vec<Statement>,
+    pub arguments: Vec<ArgumentSpec>,
+    pub returns: Option<Type>,
     pub elements: Elements,
+    pub is_async: bool,
 }
 
 impl MethodSpec {
@@ -14,10 +16,17 @@ impl MethodSpec {
             name: name.to_owned(),
             decorators: Vec::new(),
             arguments: Vec::new(),
+            returns: None,
             elements: Elements::new(),
+            is_async: false,
         }
     }
 
+    /// Declare the method with `async def`.
+    pub fn asynchronous(&mut self) {
+        self.is_async = true;
+    }
+
     pub fn push_decorator<D>(&mut self, decorator: D)
     where
         D: Into<DecoratorSpec>,
@@ -25,13 +34,20 @@ impl MethodSpec {
         self.decorators.push(decorator.into());
     }
 
-    pub fn push_argument<S>(&mut self, argument: S)
+    pub fn push_argument<A>(&mut self, argument: A)
     where
-        S: Into<Statement>,
+        A: Into<ArgumentSpec>,
     {
         self.arguments.push(argument.into());
     }
 
+    pub fn returns<T>(&mut self, returns: T)
+    where
+        T: Into<Type>,
+    {
+        self.returns = Some(returns.into());
+    }
+
     pub fn push<E>(&mut self, element: E)
     where
         E: Into<Element>,
@@ -42,34 +58,6 @@ impl MethodSpec {
 
 impl From<MethodSpec> for Element {
     fn from(value: MethodSpec) -> Element {
-        let mut out: Vec<Element> = Vec::new();
-
-        for decorator in value.decorators {
-            out.push(decorator.into());
-        }
-
-        let mut decl = Statement::new();
-        decl.push("def ");
-        decl.push(value.name);
-        decl.push("(");
-
-        let mut arguments = Statement::new();
-
-        for argument in value.arguments {
-            arguments.push(argument);
-        }
-
-        decl.push(arguments.join(", "));
-        decl.push("):");
-
-        out.push(decl.into());
-
-        if value.elements.is_empty() {
-            out.push(Nested(Box::new("pass".into())));
-        } else {
-            out.push(Nested(Box::new(value.elements.into())));
-        }
-
-        Inner(out)
+        FunctionSpec::from(value).into()
     }
 }
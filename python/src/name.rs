@@ -14,14 +14,37 @@ impl Name {
             module: module.to_owned(),
             name: name.to_owned(),
             alias: None,
+            style: None,
         }
     }
 
+    /// Import the module as `alias`, and refer to this name as `alias.name`.
     pub fn imported_alias(module: &str, name: &str, alias: &str) -> ImportedName {
         ImportedName {
             module: module.to_owned(),
             name: name.to_owned(),
             alias: Some(alias.to_owned()),
+            style: Some(ImportStyle::Module),
+        }
+    }
+
+    /// Import the name with `from module import name`, regardless of the style of the file.
+    pub fn imported_from(module: &str, name: &str) -> ImportedName {
+        ImportedName {
+            module: module.to_owned(),
+            name: name.to_owned(),
+            alias: None,
+            style: Some(ImportStyle::From),
+        }
+    }
+
+    /// Import the name with `from module import name as alias`.
+    pub fn imported_from_alias(module: &str, name: &str, alias: &str) -> ImportedName {
+        ImportedName {
+            module: module.to_owned(),
+            name: name.to_owned(),
+            alias: Some(alias.to_owned()),
+            style: Some(ImportStyle::From),
         }
     }
 
@@ -33,13 +56,15 @@ impl Name {
         LocalName { name: name.to_owned() }
     }
 
-    pub fn format<E>(&self, out: &mut E) -> Result<()>
+    pub fn format<E>(&self, out: &mut E, extra: &Extra) -> Result<()>
     where
         E: ElementFormat,
     {
         match *self {
             Name::Imported(ref imported) => {
-                if let Some(ref alias) = imported.alias {
+                if let Some(name) = extra.names.get(imported) {
+                    out.write_str(name)?;
+                } else if let Some(ref alias) = imported.alias {
                     write!(out, "{}.{}", alias, imported.name.clone())?;
                 } else {
                     write!(out, "{}.{}", imported.module, imported.name.clone())?;
@@ -53,11 +78,28 @@ impl Name {
     }
 }
 
+/// How names are imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ImportStyle {
+    /// `import module`, where names are referenced as `module.name`.
+    Module,
+    /// `from module import name`, where names are referenced as `name`.
+    From,
+}
+
+impl Default for ImportStyle {
+    fn default() -> ImportStyle {
+        ImportStyle::Module
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ImportedName {
     pub module: String,
     pub name: String,
     pub alias: Option<String>,
+    /// Style to import this name with, or `None` to use the style of the file.
+    pub style: Option<ImportStyle>,
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,55 @@
+use super::*;
+
+/// Builds a subclass of `typing.NamedTuple`.
+#[derive(Debug, Clone)]
+pub struct NamedTupleSpec {
+    pub name: String,
+    pub fields: Vec<FieldSpec>,
+}
+
+impl NamedTupleSpec {
+    pub fn new(name: &str) -> NamedTupleSpec {
+        NamedTupleSpec {
+            name: name.to_owned(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Push a field, a `default_factory` is not supported by named tuples and is ignored.
+    pub fn push_field<F>(&mut self, field: F)
+    where
+        F: Into<FieldSpec>,
+    {
+        self.fields.push(field.into());
+    }
+
+    pub fn build(self) -> ClassSpec {
+        let mut class = ClassSpec::new(&self.name);
+        class.extends(typing("NamedTuple"));
+
+        let mut fields = Elements::new();
+
+        for field in self.fields {
+            let value = field.default.clone();
+            fields.push(field.declaration(value));
+        }
+
+        if !fields.is_empty() {
+            class.push(fields);
+        }
+
+        class
+    }
+}
+
+impl From<NamedTupleSpec> for ClassSpec {
+    fn from(value: NamedTupleSpec) -> ClassSpec {
+        value.build()
+    }
+}
+
+impl From<NamedTupleSpec> for Element {
+    fn from(value: NamedTupleSpec) -> Element {
+        value.build().into()
+    }
+}
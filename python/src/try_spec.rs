@@ -0,0 +1,143 @@
+use super::*;
+
+/// An `except` clause of a `try` statement.
+#[derive(Debug, Clone)]
+pub struct ExceptSpec {
+    /// Exception types caught, catching everything if empty.
+    pub types: Vec<Name>,
+    pub alias: Option<String>,
+    pub elements: Elements,
+}
+
+/// A `try` statement, with `except`, `else` and `finally` clauses.
+#[derive(Debug, Clone)]
+pub struct TrySpec {
+    pub elements: Elements,
+    pub handlers: Vec<ExceptSpec>,
+    pub else_body: Option<Elements>,
+    pub finally_body: Option<Elements>,
+}
+
+impl TrySpec {
+    pub fn new() -> TrySpec {
+        TrySpec {
+            elements: Elements::new(),
+            handlers: Vec::new(),
+            else_body: None,
+            finally_body: None,
+        }
+    }
+
+    pub fn body(&mut self) -> &mut Elements {
+        &mut self.elements
+    }
+
+    /// Add an `except` clause catching the given types, and return its body.
+    pub fn except<N>(&mut self, types: Vec<N>, alias: Option<&str>) -> &mut Elements
+    where
+        N: Into<Name>,
+    {
+        self.handlers.push(ExceptSpec {
+            types: types.into_iter().map(Into::into).collect(),
+            alias: alias.map(ToOwned::to_owned),
+            elements: Elements::new(),
+        });
+
+        let last = self.handlers.len() - 1;
+        &mut self.handlers[last].elements
+    }
+
+    /// The body of the `else` clause, which runs if no exception was raised.
+    pub fn otherwise(&mut self) -> &mut Elements {
+        self.else_body.get_or_insert_with(Elements::new)
+    }
+
+    /// The body of the `finally` clause.
+    pub fn finally(&mut self) -> &mut Elements {
+        self.finally_body.get_or_insert_with(Elements::new)
+    }
+
+    /// Check that this is a valid `try` statement.
+    pub fn validate(&self) -> Result<()> {
+        match self.problem() {
+            Some(reason) => Err(ErrorKind::InvalidStatement(reason.to_owned()).into()),
+            None => Ok(()),
+        }
+    }
+
+    fn problem(&self) -> Option<&'static str> {
+        if self.handlers.is_empty() && self.finally_body.is_none() {
+            return Some("`try` requires an `except` or `finally` clause");
+        }
+
+        if self.handlers.is_empty() && self.else_body.is_some() {
+            return Some("`else` of a `try` requires an `except` clause");
+        }
+
+        if self.handlers.iter().filter(|h| h.types.is_empty()).count() > 1 {
+            return Some("`try` can only have one bare `except` clause");
+        }
+
+        if self.handlers.iter().any(|h| h.types.is_empty() && h.alias.is_some()) {
+            return Some("a bare `except` clause can't have an alias");
+        }
+
+        None
+    }
+}
+
+impl From<TrySpec> for Element {
+    fn from(value: TrySpec) -> Element {
+        if let Some(reason) = value.problem() {
+            return invalid(reason);
+        }
+
+        let mut out = Elements::new();
+        out.push(keyword_block("try", value.elements));
+
+        // a bare `except` has to be the last handler.
+        let (bare, typed): (Vec<_>, Vec<_>) =
+            value.handlers.into_iter().partition(|h| h.types.is_empty());
+
+        for handler in typed.into_iter().chain(bare) {
+            let mut header = Statement::new();
+            header.push("except");
+
+            let parenthesize = handler.types.len() > 1;
+            let mut types = Statement::new();
+
+            for ty in handler.types {
+                types.push(ty);
+            }
+
+            if !types.parts.is_empty() {
+                header.push(" ");
+
+                if parenthesize {
+                    header.push("(");
+                    header.push(types.join(", "));
+                    header.push(")");
+                } else {
+                    header.push(types);
+                }
+            }
+
+            if let Some(alias) = handler.alias {
+                header.push(" as ");
+                header.push(alias);
+            }
+
+            out.push(block(header, handler.elements));
+        }
+
+        if let Some(body) = value.else_body {
+            out.push(keyword_block("else", body));
+        }
+
+        if let Some(body) = value.finally_body {
+            out.push(keyword_block("finally", body));
+        }
+
+        out.into()
+    }
+}
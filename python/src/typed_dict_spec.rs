@@ -0,0 +1,65 @@
+use super::*;
+
+/// Builds a subclass of `typing.TypedDict`.
+#[derive(Debug, Clone)]
+pub struct TypedDictSpec {
+    pub name: String,
+    pub fields: Vec<FieldSpec>,
+    pub total: bool,
+}
+
+impl TypedDictSpec {
+    pub fn new(name: &str) -> TypedDictSpec {
+        TypedDictSpec {
+            name: name.to_owned(),
+            fields: Vec::new(),
+            total: true,
+        }
+    }
+
+    /// Push a field, defaults are not supported by typed dicts and are ignored.
+    pub fn push_field<F>(&mut self, field: F)
+    where
+        F: Into<FieldSpec>,
+    {
+        self.fields.push(field.into());
+    }
+
+    /// Make all keys optional, with `total=False`.
+    pub fn non_total(&mut self) {
+        self.total = false;
+    }
+
+    pub fn build(self) -> ClassSpec {
+        let mut class = ClassSpec::new(&self.name);
+        class.extends(typing("TypedDict"));
+
+        if !self.total {
+            class.push_keyword("total", "False");
+        }
+
+        let mut fields = Elements::new();
+
+        for field in self.fields {
+            fields.push(field.declaration(None));
+        }
+
+        if !fields.is_empty() {
+            class.push(fields);
+        }
+
+        class
+    }
+}
+
+impl From<TypedDictSpec> for ClassSpec {
+    fn from(value: TypedDictSpec) -> ClassSpec {
+        value.build()
+    }
+}
+
+impl From<TypedDictSpec> for Element {
+    fn from(value: TypedDictSpec) -> Element {
+        value.build().into()
+    }
+}
@@ -1,5 +1,5 @@
 use super::*;
-use codeviz_common::{ElementFormat, VariableFormat};
+use codeviz_common::{ElementFormat, ElementFormatter, VariableFormat};
 
 /// Variables that are part of statements.
 #[derive(Debug, Clone)]
@@ -12,12 +12,34 @@ pub enum Variable {
     Statement(Statement),
     /// A name that will be appended.
     Name(Name),
+    /// A type that will be appended, like the argument of a runtime `cast`.
+    Type(Type),
+    /// A type annotation that will be appended.
+    ///
+    /// Names which are only used in annotations can be imported when type checking.
+    Annotation(Type),
+    /// String that will be appended as a raw string, like `r"\\d+"`.
+    Raw(String),
+    /// Bytes that will be appended as a bytes literal, like `b"\\x00"`.
+    Bytes(Vec<u8>),
+    /// String that will be dedented and appended as a triple-quoted string.
+    TripleQuoted(String),
+    /// String that will be dedented and appended as a docstring.
+    ///
+    /// Unlike `TripleQuoted`, each line is indented with the block the docstring is in.
+    Docstring(String),
+    /// An f-string, like `f"hello {name}"`.
+    FString(Vec<FStringPart>),
+    /// A class or function definition.
+    Definition(Box<Definition>),
+    /// A statement which can't be built, failing to format with the given reason.
+    Invalid(String),
 }
 
 impl VariableFormat for Variable {
-    type Extra = ();
+    type Extra = Extra;
 
-    fn format<E>(&self, out: &mut E, depth: usize, extra: &mut Self::Extra) -> Result<()>
+    fn format<E>(&self, out: &mut E, depth: usize, extra: &mut Extra) -> Result<()>
     where
         E: ElementFormat,
     {
@@ -32,7 +54,32 @@ impl VariableFormat for Variable {
                 out.write_str(content)?;
             }
             Variable::Name(ref name) => {
-                name.format(out)?;
+                name.format(out, extra)?;
+            }
+            Variable::Type(ref ty) | Variable::Annotation(ref ty) => {
+                ty.format(out, extra)?;
+            }
+            Variable::Raw(ref string) => {
+                raw_string(out, string)?;
+            }
+            Variable::Bytes(ref bytes) => {
+                bytes_literal(out, bytes)?;
+            }
+            Variable::TripleQuoted(ref string) => {
+                triple_quoted(out, string, false)?;
+            }
+            Variable::Docstring(ref string) => {
+                triple_quoted(out, string, true)?;
+            }
+            Variable::FString(ref parts) => {
+                format_f_string(out, parts, depth, extra)?;
+            }
+            Variable::Definition(ref definition) => {
+                let elements: Elements = (**definition).clone().into();
+                Element::from(elements).format(out, extra)?;
+            }
+            Variable::Invalid(ref reason) => {
+                return Err(ErrorKind::InvalidStatement(reason.clone()).into());
             }
         }
 
@@ -67,28 +114,366 @@ impl From<Statement> for Variable {
     }
 }
 
+/// A part of an f-string.
+#[derive(Debug, Clone)]
+pub enum FStringPart {
+    /// Text that will be escaped and appended.
+    Text(String),
+    /// A statement that will be interpolated, like `{value}`.
+    Expression(Statement),
+}
+
+impl<'a> From<&'a str> for FStringPart {
+    fn from(value: &'a str) -> FStringPart {
+        FStringPart::Text(value.to_owned())
+    }
+}
+
+impl From<Statement> for FStringPart {
+    fn from(value: Statement) -> FStringPart {
+        FStringPart::Expression(value)
+    }
+}
+
+/// Pick the quote which requires the fewest escapes, preferring double quotes.
+fn pick_quote<'a, I>(texts: I) -> char
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut single = 0;
+    let mut double = 0;
+
+    for text in texts {
+        single += text.matches('\'').count();
+        double += text.matches('"').count();
+    }
+
+    if double > single {
+        '\''
+    } else {
+        '"'
+    }
+}
+
+/// Escape a single character inside of a literal delimited by `quote`.
+fn escape_char<E>(out: &mut E, c: char, quote: char) -> Result<()>
+where
+    E: ElementFormat,
+{
+    match c {
+        '\t' => out.write_str("\\t")?,
+        '\n' => out.write_str("\\n")?,
+        '\r' => out.write_str("\\r")?,
+        '\\' => out.write_str("\\\\")?,
+        c if c == quote => {
+            out.write_char('\\')?;
+            out.write_char(c)?;
+        }
+        c if c.is_control() => write!(out, "\\x{:02x}", c as u32)?,
+        c => out.write_char(c)?,
+    }
+
+    Ok(())
+}
+
 /// Quote a string to make it suitable as a literal Python string.
 fn quote_string<E>(out: &mut E, input: &str) -> Result<()>
 where
     E: ElementFormat,
 {
-    out.write_char('"')?;
+    let quote = pick_quote(Some(input));
+
+    out.write_char(quote)?;
 
     for c in input.chars() {
-        match c {
-            '\t' => out.write_str("\\t"),
-            '\u{0007}' => out.write_str("\\b"),
-            '\n' => out.write_str("\\n"),
-            '\r' => out.write_str("\\r"),
-            '\u{0014}' => out.write_str("\\f"),
-            '\'' => out.write_str("\\'"),
-            '"' => out.write_str("\\\""),
-            '\\' => out.write_str("\\\\"),
-            c => out.write_char(c),
-        }?;
+        escape_char(out, c, quote)?;
+    }
+
+    out.write_char(quote)?;
+
+    Ok(())
+}
+
+/// Write a raw string, like `r"\d+"`.
+///
+/// Falls back to a regular string if the input can't be represented as a raw string.
+fn raw_string<E>(out: &mut E, input: &str) -> Result<()>
+where
+    E: ElementFormat,
+{
+    // raw strings can't end in an odd number of backslashes.
+    let trailing = input.chars().rev().take_while(|c| *c == '\\').count();
+
+    let quote = if input.contains('"') { '\'' } else { '"' };
+
+    let representable = trailing % 2 == 0 && !input.contains(quote) &&
+        !input.chars().any(|c| c.is_control());
+
+    if !representable {
+        return quote_string(out, input);
+    }
+
+    out.write_char('r')?;
+    out.write_char(quote)?;
+    out.write_str(input)?;
+    out.write_char(quote)?;
+
+    Ok(())
+}
+
+/// Write a bytes literal, like `b"\x00\xff"`.
+fn bytes_literal<E>(out: &mut E, input: &[u8]) -> Result<()>
+where
+    E: ElementFormat,
+{
+    let single = input.iter().filter(|b| **b == b'\'').count();
+    let double = input.iter().filter(|b| **b == b'"').count();
+    let quote = if double > single { b'\'' } else { b'"' };
+
+    out.write_char('b')?;
+    out.write_char(quote as char)?;
+
+    for &b in input {
+        match b {
+            b'\t' => out.write_str("\\t")?,
+            b'\n' => out.write_str("\\n")?,
+            b'\r' => out.write_str("\\r")?,
+            b'\\' => out.write_str("\\\\")?,
+            b if b == quote => {
+                out.write_char('\\')?;
+                out.write_char(b as char)?;
+            }
+            0x20..=0x7e => out.write_char(b as char)?,
+            b => write!(out, "\\x{:02x}", b)?,
+        }
     }
 
-    out.write_char('"')?;
+    out.write_char(quote as char)?;
 
     Ok(())
 }
+
+/// Write a triple-quoted string.
+///
+/// Like `inspect.cleandoc`, leading and trailing blank lines and common indentation are
+/// removed. Lines of a docstring are written at the current indentation.
+fn triple_quoted<E>(out: &mut E, input: &str, docstring: bool) -> Result<()>
+where
+    E: ElementFormat,
+{
+    let lines = dedent(input);
+
+    // a quote at the end of the string would merge with the closing quotes.
+    let last_quote = lines.last().map(|l| l.ends_with('"')).unwrap_or(false);
+
+    out.write_str("\"\"\"")?;
+
+    for (index, line) in lines.iter().enumerate() {
+        if index > 0 {
+            // only docstrings follow the indentation of their block, since it would otherwise
+            // become part of the string.
+            if docstring {
+                out.new_line()?;
+            } else {
+                out.write_char('\n')?;
+            }
+        }
+
+        let mut line = *line;
+
+        if !docstring && last_quote && index + 1 == lines.len() {
+            line = &line[..line.len() - 1];
+        }
+
+        let mut quotes = 0;
+
+        for c in line.chars() {
+            if c == '"' {
+                quotes += 1;
+
+                // break up any sequence of quotes which would close the string.
+                if quotes == 3 {
+                    out.write_str("\\\"")?;
+                    quotes = 0;
+                } else {
+                    out.write_char('"')?;
+                }
+
+                continue;
+            }
+
+            quotes = 0;
+
+            match c {
+                '\t' => out.write_char('\t')?,
+                c => escape_char(out, c, '"')?,
+            }
+        }
+    }
+
+    if docstring {
+        if lines.len() > 1 || last_quote {
+            out.new_line()?;
+        }
+    } else if last_quote {
+        out.write_str("\\\"")?;
+    }
+
+    out.write_str("\"\"\"")?;
+
+    Ok(())
+}
+
+/// Remove leading and trailing blank lines, and the common indentation of all other lines.
+fn dedent(input: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = input.lines().collect();
+
+    while lines.first().map(|l| l.trim().is_empty()).unwrap_or(false) {
+        lines.remove(0);
+    }
+
+    while lines.last().map(|l| l.trim().is_empty()).unwrap_or(false) {
+        lines.pop();
+    }
+
+    // only spaces and tabs count as indentation, so that slicing stays on a char boundary.
+    let indent = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start_matches([' ', '\t']).len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .into_iter()
+        .map(|l| if l.trim().is_empty() { "" } else { &l[indent..] })
+        .collect()
+}
+
+fn format_f_string<E>(
+    out: &mut E,
+    parts: &[FStringPart],
+    depth: usize,
+    extra: &mut Extra,
+) -> Result<()>
+where
+    E: ElementFormat,
+{
+    let mut expressions = Vec::new();
+
+    for part in parts {
+        if let FStringPart::Expression(ref stmt) = *part {
+            let mut expression = String::new();
+            stmt.format(&mut ElementFormatter::new(&mut expression), depth, extra)?;
+            expressions.push(expression);
+        }
+    }
+
+    let quote = pick_quote(parts.iter().filter_map(|p| match *p {
+        FStringPart::Text(ref text) => Some(text.as_str()),
+        FStringPart::Expression(_) => None,
+    }));
+
+    // expressions can't contain backslashes or the enclosing quote before Python 3.12.
+    let usable = |quote: char| !expressions.iter().any(|e| e.contains(quote));
+
+    if let Some(expression) = expressions.iter().find(|e| e.contains('\\')) {
+        return Err(ErrorKind::InvalidFString(expression.clone()).into());
+    }
+
+    if let Some(expression) = expressions.iter().find(|e| has_f_string_delimiter(e)) {
+        return Err(ErrorKind::InvalidFString(expression.clone()).into());
+    }
+
+    let quote = match quote {
+        quote if usable(quote) => quote,
+        '"' if usable('\'') => '\'',
+        '\'' if usable('"') => '"',
+        quote => {
+            let expression = expressions.iter().find(|e| e.contains(quote)).cloned();
+            return Err(ErrorKind::InvalidFString(expression.unwrap_or_default()).into());
+        }
+    };
+
+    let mut expressions = expressions.into_iter();
+
+    out.write_char('f')?;
+    out.write_char(quote)?;
+
+    for part in parts {
+        match *part {
+            FStringPart::Text(ref text) => {
+                for c in text.chars() {
+                    match c {
+                        '{' => out.write_str("{{")?,
+                        '}' => out.write_str("}}")?,
+                        c => escape_char(out, c, quote)?,
+                    }
+                }
+            }
+            FStringPart::Expression(_) => {
+                let expression = expressions.next().unwrap_or_default();
+
+                // braces next to the delimiters would be read as escaped braces.
+                if expression.starts_with('{') || expression.ends_with('}') {
+                    out.write_str("{ ")?;
+                    out.write_str(&expression)?;
+                    out.write_str(" }")?;
+                } else {
+                    out.write_char('{')?;
+                    out.write_str(&expression)?;
+                    out.write_char('}')?;
+                }
+            }
+        }
+    }
+
+    out.write_char(quote)?;
+
+    Ok(())
+}
+
+/// Check if an f-string expression contains a `!`, `:` or `=` outside of brackets and strings,
+/// which Python would read as the start of a conversion, format spec or `=` specifier.
+fn has_f_string_delimiter(expression: &str) -> bool {
+    let mut depth = 0usize;
+    let mut string = None;
+    let mut previous = None;
+    let mut chars = expression.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if let Some(quote) = string {
+            if c == quote {
+                string = None;
+            }
+        } else {
+            match c {
+                '\'' | '"' => string = Some(c),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth = depth.saturating_sub(1),
+                '!' | ':' | '=' if depth == 0 => {
+                    let next = chars.peek().cloned();
+
+                    // comparisons like `!=` and `<=` are fine.
+                    let comparison = match c {
+                        '!' => next == Some('='),
+                        '=' => {
+                            next == Some('=') ||
+                                previous.map(|p| "=!<>".contains(p)).unwrap_or(false)
+                        }
+                        _ => false,
+                    };
+
+                    if !comparison {
+                        return true;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        previous = Some(c);
+    }
+
+    false
+}
@@ -0,0 +1,192 @@
+use codeviz_common::{Visitor, VisitorMut};
+use super::*;
+
+/// Walk the children of a variable, like the body of a definition or the names of a type.
+///
+/// Names which are not variables themselves, like those in types and decorators, are visited as
+/// `Variable::Name`.
+pub fn walk_variable<V>(visitor: &mut V, variable: &Variable)
+where
+    V: Visitor<Variable> + ?Sized,
+{
+    match *variable {
+        Variable::Statement(ref stmt) => {
+            visitor.visit_statement(stmt);
+        }
+        Variable::Type(ref ty) | Variable::Annotation(ref ty) => {
+            walk_type(visitor, ty);
+        }
+        Variable::FString(ref parts) => {
+            for part in parts {
+                if let FStringPart::Expression(ref stmt) = *part {
+                    visitor.visit_statement(stmt);
+                }
+            }
+        }
+        Variable::Definition(ref definition) => {
+            for decorator in &definition.decorators {
+                walk_decorator(visitor, decorator);
+            }
+
+            visitor.visit_statement(&definition.declaration);
+            visitor.visit_elements(&definition.body);
+        }
+        Variable::Literal(_) |
+        Variable::String(_) |
+        Variable::Name(_) |
+        Variable::Raw(_) |
+        Variable::Bytes(_) |
+        Variable::TripleQuoted(_) |
+        Variable::Docstring(_) |
+        Variable::Invalid(_) => {}
+    }
+}
+
+/// Walk the children of a variable, permitting them to be rewritten.
+///
+/// See `walk_variable`.
+pub fn walk_variable_mut<V>(visitor: &mut V, variable: &mut Variable)
+where
+    V: VisitorMut<Variable> + ?Sized,
+{
+    match *variable {
+        Variable::Statement(ref mut stmt) => {
+            visitor.visit_statement_mut(stmt);
+        }
+        Variable::Type(ref mut ty) | Variable::Annotation(ref mut ty) => {
+            walk_type_mut(visitor, ty);
+        }
+        Variable::FString(ref mut parts) => {
+            for part in parts {
+                if let FStringPart::Expression(ref mut stmt) = *part {
+                    visitor.visit_statement_mut(stmt);
+                }
+            }
+        }
+        Variable::Definition(ref mut definition) => {
+            for decorator in &mut definition.decorators {
+                walk_decorator_mut(visitor, decorator);
+            }
+
+            visitor.visit_statement_mut(&mut definition.declaration);
+            visitor.visit_elements_mut(&mut definition.body);
+        }
+        Variable::Literal(_) |
+        Variable::String(_) |
+        Variable::Name(_) |
+        Variable::Raw(_) |
+        Variable::Bytes(_) |
+        Variable::TripleQuoted(_) |
+        Variable::Docstring(_) |
+        Variable::Invalid(_) => {}
+    }
+}
+
+fn walk_name<V>(visitor: &mut V, name: &Name)
+where
+    V: Visitor<Variable> + ?Sized,
+{
+    visitor.visit_variable(&Variable::Name(name.clone()));
+}
+
+fn walk_name_mut<V>(visitor: &mut V, name: &mut Name)
+where
+    V: VisitorMut<Variable> + ?Sized,
+{
+    let mut variable = Variable::Name(name.clone());
+    visitor.visit_variable_mut(&mut variable);
+
+    if let Variable::Name(rewritten) = variable {
+        *name = rewritten;
+    }
+}
+
+fn walk_decorator<V>(visitor: &mut V, decorator: &DecoratorSpec)
+where
+    V: Visitor<Variable> + ?Sized,
+{
+    walk_name(visitor, &decorator.name);
+
+    for argument in &decorator.arguments {
+        visitor.visit_statement(argument);
+    }
+
+    for (_, argument) in &decorator.keywords {
+        visitor.visit_statement(argument);
+    }
+}
+
+fn walk_decorator_mut<V>(visitor: &mut V, decorator: &mut DecoratorSpec)
+where
+    V: VisitorMut<Variable> + ?Sized,
+{
+    walk_name_mut(visitor, &mut decorator.name);
+
+    for argument in &mut decorator.arguments {
+        visitor.visit_statement_mut(argument);
+    }
+
+    for (_, argument) in &mut decorator.keywords {
+        visitor.visit_statement_mut(argument);
+    }
+}
+
+fn walk_type<V>(visitor: &mut V, ty: &Type)
+where
+    V: Visitor<Variable> + ?Sized,
+{
+    match *ty {
+        Type::Name(ref name) => walk_name(visitor, name),
+        Type::Generic(ref base, ref arguments) => {
+            walk_type(visitor, base);
+
+            for argument in arguments {
+                walk_type(visitor, argument);
+            }
+        }
+        Type::Optional(ref inner) => walk_type(visitor, inner),
+        Type::Union(ref types) => {
+            for ty in types {
+                walk_type(visitor, ty);
+            }
+        }
+        Type::Callable(ref arguments, ref returns) => {
+            for argument in arguments {
+                walk_type(visitor, argument);
+            }
+
+            walk_type(visitor, returns);
+        }
+        Type::None => {}
+    }
+}
+
+fn walk_type_mut<V>(visitor: &mut V, ty: &mut Type)
+where
+    V: VisitorMut<Variable> + ?Sized,
+{
+    match *ty {
+        Type::Name(ref mut name) => walk_name_mut(visitor, name),
+        Type::Generic(ref mut base, ref mut arguments) => {
+            walk_type_mut(visitor, base);
+
+            for argument in arguments {
+                walk_type_mut(visitor, argument);
+            }
+        }
+        Type::Optional(ref mut inner) => walk_type_mut(visitor, inner),
+        Type::Union(ref mut types) => {
+            for ty in types {
+                walk_type_mut(visitor, ty);
+            }
+        }
+        Type::Callable(ref mut arguments, ref mut returns) => {
+            for argument in arguments {
+                walk_type_mut(visitor, argument);
+            }
+
+            walk_type_mut(visitor, returns);
+        }
+        Type::None => {}
+    }
+}
@@ -0,0 +1,48 @@
+use super::*;
+
+/// A `while` loop, with an optional `else` branch.
+#[derive(Debug, Clone)]
+pub struct WhileSpec {
+    pub condition: Statement,
+    pub elements: Elements,
+    pub else_body: Option<Elements>,
+}
+
+impl WhileSpec {
+    pub fn new<S>(condition: S) -> WhileSpec
+    where
+        S: Into<Statement>,
+    {
+        WhileSpec {
+            condition: condition.into(),
+            elements: Elements::new(),
+            else_body: None,
+        }
+    }
+
+    pub fn body(&mut self) -> &mut Elements {
+        &mut self.elements
+    }
+
+    /// The body of the `else` branch, which runs if the loop wasn't broken out of.
+    pub fn otherwise(&mut self) -> &mut Elements {
+        self.else_body.get_or_insert_with(Elements::new)
+    }
+}
+
+impl From<WhileSpec> for Element {
+    fn from(value: WhileSpec) -> Element {
+        let mut header = Statement::new();
+        header.push("while ");
+        header.push(value.condition);
+
+        let mut out = Elements::new();
+        out.push(block(header, value.elements));
+
+        if let Some(body) = value.else_body {
+            out.push(keyword_block("else", body));
+        }
+
+        out.into()
+    }
+}
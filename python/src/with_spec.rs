@@ -0,0 +1,88 @@
+use super::*;
+
+const NO_ITEMS: &str = "`with` requires a context manager";
+
+/// A `with` statement, entering one or more context managers.
+#[derive(Debug, Clone)]
+pub struct WithSpec {
+    /// Context managers, and the names they are bound to.
+    pub items: Vec<(Statement, Option<String>)>,
+    pub elements: Elements,
+    pub is_async: bool,
+}
+
+impl WithSpec {
+    pub fn new() -> WithSpec {
+        WithSpec {
+            items: Vec::new(),
+            elements: Elements::new(),
+            is_async: false,
+        }
+    }
+
+    /// Enter the context manager with `async with`.
+    pub fn asynchronous(&mut self) {
+        self.is_async = true;
+    }
+
+    pub fn push_item<S>(&mut self, item: S)
+    where
+        S: Into<Statement>,
+    {
+        self.items.push((item.into(), None));
+    }
+
+    /// Push a context manager which is bound to `name`, like `open(path) as f`.
+    pub fn push_item_as<S>(&mut self, item: S, name: &str)
+    where
+        S: Into<Statement>,
+    {
+        self.items.push((item.into(), Some(name.to_owned())));
+    }
+
+    pub fn body(&mut self) -> &mut Elements {
+        &mut self.elements
+    }
+
+    /// Check that this is a valid `with` statement.
+    pub fn validate(&self) -> Result<()> {
+        if self.items.is_empty() {
+            return Err(ErrorKind::InvalidStatement(NO_ITEMS.to_owned()).into());
+        }
+
+        Ok(())
+    }
+}
+
+impl From<WithSpec> for Element {
+    fn from(value: WithSpec) -> Element {
+        if value.items.is_empty() {
+            return invalid(NO_ITEMS);
+        }
+
+        let mut items = Statement::new();
+
+        for (item, name) in value.items {
+            let mut s = Statement::new();
+            s.push(item);
+
+            if let Some(name) = name {
+                s.push(" as ");
+                s.push(name);
+            }
+
+            items.push(s);
+        }
+
+        let mut header = Statement::new();
+
+        if value.is_async {
+            header.push("async ");
+        }
+
+        header.push("with ");
+        header.push(items.join(", "));
+
+        block(header, value.elements)
+    }
+}
@@ -1,3 +1,4 @@
+use codeviz_common::Walk;
 use super::*;
 
 pub trait ImportReceiver {
@@ -54,7 +55,7 @@ impl Imports for Statement {
     where
         I: ImportReceiver,
     {
-        receiver.import_all(&self.parts);
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
     }
 }
 
@@ -63,31 +64,20 @@ impl Imports for Element {
     where
         I: ImportReceiver,
     {
-        match *self {
-            Push(ref statement) => {
-                statement.imports(receiver);
-            }
-            Inner(ref elements) => {
-                receiver.import_all(elements);
-            }
-            Nested(ref element) => {
-                element.imports(receiver);
-            }
-            _ => {}
-        };
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
     }
 }
 
-impl Imports for StructSpec {
+impl Imports for Elements {
     fn imports<I>(&self, receiver: &mut I)
     where
         I: ImportReceiver,
     {
-        self.elements.imports(receiver);
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
     }
 }
 
-impl Imports for EnumSpec {
+impl Imports for StructSpec {
     fn imports<I>(&self, receiver: &mut I)
     where
         I: ImportReceiver,
@@ -96,11 +86,11 @@ impl Imports for EnumSpec {
     }
 }
 
-impl Imports for Elements {
+impl Imports for EnumSpec {
     fn imports<I>(&self, receiver: &mut I)
     where
         I: ImportReceiver,
     {
-        receiver.import_all(&self.elements);
+        self.elements.imports(receiver);
     }
 }
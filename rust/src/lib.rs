@@ -6,6 +6,7 @@ mod file_spec;
 mod imports;
 mod name;
 mod variable;
+mod visitor;
 
 pub use codeviz_common::Element::*;
 pub use self::enum_spec::*;
@@ -14,6 +15,7 @@ pub use self::imports::*;
 pub use self::name::*;
 pub use self::struct_spec::*;
 pub use self::variable::*;
+pub use self::visitor::*;
 pub(crate) use self::errors::*;
 
 pub mod errors {
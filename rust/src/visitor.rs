@@ -0,0 +1,24 @@
+use codeviz_common::{Visitor, VisitorMut};
+use super::*;
+
+/// Walk the children of a variable, like the parts of a nested statement.
+pub fn walk_variable<V>(visitor: &mut V, variable: &Variable)
+where
+    V: Visitor<Variable> + ?Sized,
+{
+    match *variable {
+        Variable::Statement(ref stmt) => visitor.visit_statement(stmt),
+        Variable::Literal(_) | Variable::String(_) | Variable::Name(_) => {}
+    }
+}
+
+/// Walk the children of a variable, permitting them to be rewritten.
+pub fn walk_variable_mut<V>(visitor: &mut V, variable: &mut Variable)
+where
+    V: VisitorMut<Variable> + ?Sized,
+{
+    match *variable {
+        Variable::Statement(ref mut stmt) => visitor.visit_statement_mut(stmt),
+        Variable::Literal(_) | Variable::String(_) | Variable::Name(_) => {}
+    }
+}
@@ -6,6 +6,7 @@ pub extern crate codeviz_java as java;
 pub extern crate codeviz_js as js;
 pub extern crate codeviz_python as python;
 pub extern crate codeviz_rust as rust;
+pub extern crate codeviz_typescript as typescript;
 
 #[cfg(test)]
 mod python_tests {
@@ -34,6 +35,641 @@ mod python_tests {
         let reference = ::std::str::from_utf8(include_bytes!("tests/test.py")).unwrap();
         assert_eq!(reference, file.to_string());
     }
+
+    #[test]
+    fn test_from_imports() {
+        let optional = Name::imported("typing", "Optional");
+        let list = Name::imported("typing", "List");
+        let path = Name::imported("os", "path");
+        let other_path = Name::imported("pathlib", "path");
+        let exit = Name::imported("sys", "exit");
+        let dumps = Name::imported_from_alias("json", "dumps", "to_json");
+
+        let mut long = Vec::new();
+
+        for name in &["Alpha", "Bravo", "Charlie", "Delta", "Echo", "Foxtrot", "Golf", "Hotel"] {
+            long.push(Name::imported("phonetic.alphabet", name));
+        }
+
+        let mut body = stmt!["return ", optional, "[", list, "], ", path, ", ", other_path];
+        body.push(", ");
+        body.push(dumps);
+
+        for name in long {
+            body.push(", ");
+            body.push(name);
+        }
+
+        let mut hello = MethodSpec::new("hello");
+        hello.push(body);
+
+        let mut file = FileSpec::new();
+        file.import_style(ImportStyle::From);
+        file.push(hello);
+
+        assert_eq!(
+            "import os\nimport pathlib\n\
+             from json import dumps as to_json\n\
+             from typing import List, Optional\n\n\
+             from phonetic.alphabet import (\n  Alpha,\n  Bravo,\n  Charlie,\n  Delta,\n  \
+             Echo,\n  Foxtrot,\n  Golf,\n  Hotel,\n)\n\n\
+             def hello():\n  return Optional[List], os.path, pathlib.path, to_json, Alpha, Bravo, \
+             Charlie, Delta, Echo, Foxtrot, Golf, Hotel\n",
+            file.to_string()
+        );
+
+        let mut bye = MethodSpec::new("bye");
+        bye.push(stmt![exit, "(", Name::imported_from("sys", "argv"), ")"]);
+
+        let mut file = FileSpec::new();
+        file.push(bye);
+
+        assert_eq!(
+            "import sys\nfrom sys import argv\n\ndef bye():\n  sys.exit(argv)\n",
+            file.to_string()
+        );
+
+        let array = Name::imported_alias("numpy", "array", "np");
+
+        let mut file = FileSpec::new();
+        file.import_style(ImportStyle::From);
+        file.push(stmt![array, "([1])"]);
+
+        assert_eq!("import numpy as np\n\nnp.array([1])\n", file.to_string());
+    }
+
+    #[test]
+    fn test_argument_specs() {
+        let request = Name::imported("http", "Request");
+        let list = Name::imported("typing", "List");
+
+        let mut first = ArgumentSpec::with_kind("first", ArgumentKind::PositionalOnly);
+        first.annotation(Type::built_in("int"));
+
+        let mut name = ArgumentSpec::new("name");
+        name.annotation(Type::optional(Type::built_in("str")));
+        name.default("None");
+
+        let mut retries = ArgumentSpec::with_kind("retries", ArgumentKind::KeywordOnly);
+        retries.default("3");
+
+        let mut callback = ArgumentSpec::with_kind("callback", ArgumentKind::KeywordOnly);
+        callback.annotation(Type::callable(
+            vec![Type::name(&request)],
+            Type::union(vec![Type::built_in("bool"), Type::None]),
+        ));
+
+        let mut kwargs = ArgumentSpec::with_kind("kwargs", ArgumentKind::KwArgs);
+        kwargs.annotation(Type::built_in("str"));
+
+        let mut handle = MethodSpec::new("handle");
+        handle.push_argument(first);
+        handle.push_argument(name);
+        handle.push_argument(retries);
+        handle.push_argument(callback);
+        handle.push_argument(kwargs);
+        handle.returns(Type::generic(&list, vec![Type::name(&request)]));
+
+        let mut rest = MethodSpec::new("rest");
+        rest.push_argument(ArgumentSpec::with_kind("args", ArgumentKind::VarArgs));
+        rest.push_argument(ArgumentSpec::with_kind("key", ArgumentKind::KeywordOnly));
+
+        let ty = Type::optional(Type::name(&request));
+        let names: Vec<String> = ty.imported_names().into_iter().map(|n| n.name).collect();
+        assert_eq!(vec!["Optional".to_owned(), "Request".to_owned()], names);
+
+        let mut file = FileSpec::new();
+        file.import_style(ImportStyle::From);
+        file.push(handle);
+        file.push(rest);
+
+        assert_eq!(
+            "from http import Request\nfrom typing import Callable, List, Optional\n\n\
+             def handle(first: int, /, name: Optional[str] = None, *, retries=3, \
+             callback: Callable[[Request], bool | None], **kwargs: str) -> List[Request]:\n  \
+             pass\n\n\
+             def rest(*args, key):\n  pass\n",
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn test_data_models() {
+        let list = Name::imported("typing", "List");
+
+        let mut user = DataClassSpec::new("User");
+        user.frozen();
+        user.slots();
+        user.push_field(FieldSpec::new("name", Type::built_in("str")));
+
+        let mut tags = FieldSpec::new("tags", Type::generic(&list, vec![Type::built_in("str")]));
+        tags.default_factory("list");
+        user.push_field(tags);
+
+        let mut age = FieldSpec::new("age", Type::built_in("int"));
+        age.default("0");
+        user.push_field(age);
+
+        let mut point = NamedTupleSpec::new("Point");
+        point.push_field(FieldSpec::new("x", Type::built_in("int")));
+
+        let mut y = FieldSpec::new("y", Type::built_in("int"));
+        y.default("0");
+        point.push_field(y);
+
+        let mut movie = TypedDictSpec::new("Movie");
+        movie.non_total();
+        movie.push_field(FieldSpec::new("title", Type::built_in("str")));
+
+        let mut color = EnumSpec::new("Color");
+        color.push_member("RED", Variable::String("red".into()));
+        color.push_auto("GREEN");
+
+        let mut file = FileSpec::new();
+        file.import_style(ImportStyle::From);
+        file.push(user);
+        file.push(point);
+        file.push(movie);
+        file.push(color);
+        file.push(DataClassSpec::new("Empty"));
+
+        assert_eq!(
+            "from dataclasses import dataclass, field\nfrom enum import Enum, auto\n\
+             from typing import List, NamedTuple, TypedDict\n\n\
+             @dataclass(frozen=True, slots=True)\nclass User:\n  name: str\n  \
+             tags: List[str] = field(default_factory=list)\n  age: int = 0\n\n\
+             class Point(NamedTuple):\n  x: int\n  y: int = 0\n\n\
+             class Movie(TypedDict, total=False):\n  title: str\n\n\
+             class Color(Enum):\n  RED = \"red\"\n  GREEN = auto()\n\n\
+             @dataclass\nclass Empty:\n  pass\n",
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn test_functions() {
+        let sleep = Name::imported("asyncio", "sleep");
+
+        let mut inner = FunctionSpec::new("inner");
+        inner.push_argument("value");
+        inner.push(stmt!["return value"]);
+
+        let mut outer = FunctionSpec::new("outer");
+        outer.asynchronous();
+        outer.push(inner);
+        outer.push(stmt!["await ", sleep, "(1)"]);
+
+        let mut create = MethodSpec::new("create");
+        create.push(stmt!["return cls()"]);
+
+        let mut name = MethodSpec::new("name");
+        name.push(stmt!["return self._name"]);
+
+        let mut set_name = MethodSpec::new("set_name");
+        set_name.push_argument("value");
+        set_name.push(stmt!["self._name = value"]);
+
+        let mut run = MethodSpec::new("run");
+        run.asynchronous();
+
+        let mut foo = ClassSpec::new("Foo");
+        foo.push_method(MethodKind::Class, create);
+        foo.push_method(MethodKind::Static, MethodSpec::new("helper"));
+        foo.push_method(MethodKind::Instance, run);
+        foo.push_property(name, Some(set_name));
+
+        let mut file = FileSpec::new();
+        file.push(outer);
+        file.push(foo);
+
+        assert_eq!(
+            "import asyncio\n\n\
+             async def outer():\n  def inner(value):\n    return value\n  \
+             await asyncio.sleep(1)\n\n\
+             class Foo:\n  @classmethod\n  def create(cls):\n    return cls()\n\n  \
+             @staticmethod\n  def helper():\n    pass\n\n  \
+             async def run(self):\n    pass\n\n  \
+             @property\n  def name(self):\n    return self._name\n\n  \
+             @name.setter\n  def name(self, value):\n    self._name = value\n",
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn test_compound_statements() {
+        let timeout = Name::imported("socket", "timeout");
+        let refused = Name::imported("errors", "Refused");
+
+        let mut check = IfSpec::new("x > 0");
+        check.body().push("return 1");
+        check.elif("x < 0");
+        check.otherwise().push("return 0");
+
+        let mut each = ForSpec::new("item", "items");
+        each.body().push(check);
+        each.otherwise();
+
+        let mut forever = WhileSpec::new("True");
+        forever.body().push("break");
+
+        let mut with = WithSpec::new();
+        with.push_item_as("open(path)", "f");
+        with.push_item("lock");
+
+        let mut attempt = TrySpec::new();
+        attempt.body().push(with);
+        attempt.except(vec![&timeout, &refused], Some("e")).push("raise");
+        attempt.except(Vec::<Name>::new(), None).push("pass");
+        attempt.except(vec![&timeout], None);
+        attempt.otherwise();
+        attempt.finally().push("close()");
+
+        let mut command = MatchSpec::new("command");
+        command.case("\"go\"").push("go()");
+        command.case_guarded("n", "n > 1");
+
+        let mut run = FunctionSpec::new("run");
+        run.push(each);
+        run.push(forever);
+        run.push(attempt);
+        run.push(command);
+
+        let mut file = FileSpec::new();
+        file.push(run);
+
+        assert_eq!(
+            "import socket\n\nimport errors\n\n\
+             def run():\n  \
+             for item in items:\n    if x > 0:\n      return 1\n    elif x < 0:\n      pass\n    \
+             else:\n      return 0\n  else:\n    pass\n  \
+             while True:\n    break\n  \
+             try:\n    with open(path) as f, lock:\n      pass\n  \
+             except (socket.timeout, errors.Refused) as e:\n    raise\n  \
+             except socket.timeout:\n    pass\n  except:\n    pass\n  \
+             else:\n    pass\n  finally:\n    close()\n  \
+             match command:\n    case \"go\":\n      go()\n    case n if n > 1:\n      pass\n",
+            file.to_string()
+        );
+
+        let mut bare = TrySpec::new();
+        bare.except(Vec::<Name>::new(), None);
+        bare.except(Vec::<Name>::new(), None);
+
+        let mut aliased = TrySpec::new();
+        aliased.except(Vec::<Name>::new(), Some("e"));
+
+        let mut otherwise = TrySpec::new();
+        otherwise.otherwise();
+        otherwise.finally();
+
+        assert!(TrySpec::new().validate().is_err());
+        assert!(bare.validate().is_err());
+        assert!(aliased.validate().is_err());
+        assert!(otherwise.validate().is_err());
+        assert!(MatchSpec::new("x").validate().is_err());
+        assert!(WithSpec::new().validate().is_err());
+
+        let mut invalid = FileSpec::new();
+        invalid.push(WithSpec::new());
+        assert!(invalid.format(&mut String::new()).is_err());
+
+        for expression in &["x!r", "lambda: x", "x=", "(y := 1), y:=2"] {
+            let expression = FStringPart::Expression(stmt![*expression]);
+            let mut invalid = FileSpec::new();
+            invalid.push(stmt![Variable::FString(vec![expression])]);
+
+            assert!(invalid.format(&mut String::new()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_string_literals() {
+        let name = Name::imported("users", "name");
+
+        let greeting = Variable::FString(vec![
+            "say \"{hi}\" to ".into(),
+            FStringPart::Expression(stmt![name, ".upper()"]),
+        ]);
+
+        let doc = Variable::Docstring("\n    Summary.\n\n      Indented \"\"\"quotes\"\"\".\n    ".into());
+
+        let mut run = FunctionSpec::new("run");
+        run.push(stmt![doc]);
+        run.push(stmt!["a = ", Variable::String("it's \u{7}\u{1b}".into())]);
+        run.push(stmt!["b = ", Variable::String("say \"hi\"".into())]);
+        run.push(stmt!["c = ", Variable::Raw("\\d+\"".into())]);
+        run.push(stmt!["d = ", Variable::Raw("trailing\\".into())]);
+        run.push(stmt!["e = ", Variable::Bytes(vec![b'a', 0, 0xff, b'\n'])]);
+        run.push(stmt!["f = ", greeting]);
+        run.push(stmt!["g = ", Variable::TripleQuoted("ends with \"".into())]);
+        run.push(stmt!["h = ", Variable::TripleQuoted("  a\n\u{3000}b".into())]);
+        run.push(stmt!["i = ", Variable::String("next \u{85}line".into())]);
+        let lookup = stmt!["conf.d[", Variable::String("k".into()), "]"];
+        run.push(stmt!["j = ", Variable::FString(vec![FStringPart::Expression(lookup)])]);
+        let dict = FStringPart::Expression(stmt!["{1: 2}"]);
+        run.push(stmt!["k = ", Variable::FString(vec!["a".into(), dict])]);
+        let compare = FStringPart::Expression(stmt!["a != b <= c"]);
+        run.push(stmt!["l = ", Variable::FString(vec![compare])]);
+
+        let mut file = FileSpec::new();
+        file.push(run);
+
+        assert_eq!(
+            "import users\n\n\
+             def run():\n  \
+             \"\"\"Summary.\n\n    Indented \"\"\\\"quotes\"\"\\\".\n  \"\"\"\n  \
+             a = \"it's \\x07\\x1b\"\n  \
+             b = 'say \"hi\"'\n  \
+             c = r'\\d+\"'\n  \
+             d = \"trailing\\\\\"\n  \
+             e = b\"a\\x00\\xff\\n\"\n  \
+             f = f'say \"{{hi}}\" to {users.name.upper()}'\n  \
+             g = \"\"\"ends with \\\"\"\"\"\n  \
+             h = \"\"\"  a\n\u{3000}b\"\"\"\n  \
+             i = \"next \\x85line\"\n  \
+             j = f'{conf.d[\"k\"]}'\n  \
+             k = f\"a{ {1: 2} }\"\n  \
+             l = f\"{a != b <= c}\"\n",
+            file.to_string()
+        );
+
+        let quotes = stmt![Variable::String("a\"".into()), " + ", Variable::String("b'".into())];
+        let mut invalid = FileSpec::new();
+        invalid.push(stmt![Variable::FString(vec![FStringPart::Expression(quotes)])]);
+
+        assert!(invalid.format(&mut String::new()).is_err());
+    }
+
+    #[test]
+    fn test_import_sections() {
+        let request = Name::imported("requests", "Request");
+        let session = Name::imported("requests", "Session");
+        let model = Name::imported("app.models", "Model");
+        let config = Name::imported("app.config", "Config");
+        let path = Name::imported("os", "path");
+
+        let mut argument = ArgumentSpec::new("request");
+        argument.annotation(Type::name(&request));
+
+        let mut model_argument = ArgumentSpec::new("model");
+        model_argument.annotation(Type::optional(Type::name(&model)));
+
+        let mut handle = FunctionSpec::new("handle");
+        handle.push_argument(argument);
+        handle.push_argument(model_argument);
+        handle.returns(Type::name(&config));
+        handle.push(stmt!["return ", session, "(", path, ", ", config, ")"]);
+
+        let mut file = FileSpec::new();
+        file.import_style(ImportStyle::From);
+        file.push_first_party("app");
+        file.push_future("division");
+        file.type_checking();
+        file.push(handle);
+
+        assert_eq!(
+            "from __future__ import annotations, division\n\n\
+             from os import path\nfrom typing import TYPE_CHECKING\n\n\
+             from requests import Session\n\n\
+             from app.config import Config\n\n\
+             if TYPE_CHECKING:\n  from typing import Optional\n\n  \
+             from requests import Request\n\n  from app.models import Model\n\n\
+             def handle(request: Request, model: Optional[Model]) -> Config:\n  \
+             return Session(path, Config)\n",
+            file.to_string()
+        );
+
+        let bar = Name::imported("foo", "Bar");
+        let baz = Name::imported("baz", "Baz");
+
+        let mut alias = FileSpec::new();
+        alias.type_checking();
+        alias.push(stmt![
+            "Alias = ",
+            Type::generic(Type::built_in("list"), vec![Type::name(&bar)]),
+        ]);
+
+        let mut current = FunctionSpec::new("current");
+        current.returns(Type::name(&baz));
+        current.push(stmt!["return Alias()"]);
+        alias.push(current);
+
+        assert_eq!(
+            "from __future__ import annotations\n\nimport typing\n\nimport foo\n\n\
+             if typing.TYPE_CHECKING:\n  import baz\n\n\
+             Alias = list[foo.Bar]\n\ndef current() -> baz.Baz:\n  return Alias()\n",
+            alias.to_string()
+        );
+    }
+
+    #[test]
+    fn test_file_header() {
+        let path = Name::imported("os", "path");
+
+        let mut main = FunctionSpec::new("main");
+        main.push(stmt!["return ", path, ".sep"]);
+
+        let mut file = FileSpec::new();
+        file.shebang("/usr/bin/env python3");
+        file.encoding("utf-8");
+        file.docstring("Command line tools.");
+        file.push_future("annotations");
+        file.all();
+        file.push_export("VERSION");
+        file.push(ClassSpec::new("Tool"));
+        file.push(ClassSpec::new("_Hidden"));
+        file.push(main);
+        file.push(stmt!["VERSION = ", Variable::String("1.0".into())]);
+
+        assert_eq!(
+            "#!/usr/bin/env python3\n# -*- coding: utf-8 -*-\n\"\"\"Command line tools.\"\"\"\n\n\
+             from __future__ import annotations\n\n\
+             __all__ = [\"Tool\", \"main\", \"VERSION\"]\n\n\
+             import os\n\n\
+             class Tool:\n  pass\n\n\
+             class _Hidden:\n  pass\n\n\
+             def main():\n  return os.path.sep\n\n\
+             VERSION = \"1.0\"\n",
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn test_stub() {
+        let overload = Name::imported("typing", "overload");
+        let request = Name::imported("requests", "Request");
+        let json = Name::imported("json", "dumps");
+
+        let int = Type::name(Name::built_in("int"));
+        let str = Type::name(Name::built_in("str"));
+
+        let mut int_key = ArgumentSpec::new("key");
+        int_key.annotation(&int);
+
+        let mut get_int = MethodSpec::new("get");
+        get_int.push_decorator(&overload);
+        get_int.push_argument(int_key);
+        get_int.returns(&int);
+
+        let mut str_key = ArgumentSpec::new("key");
+        str_key.annotation(&str);
+
+        let mut get_str = MethodSpec::new("get");
+        get_str.push_decorator(&overload);
+        get_str.push_argument(str_key);
+        get_str.returns(&str);
+
+        let mut request_argument = ArgumentSpec::new("request");
+        request_argument.annotation(Type::name(&request));
+
+        let mut init = MethodSpec::new("__init__");
+        init.push_argument(request_argument);
+        init.push("self.request = request");
+
+        let mut hidden = MethodSpec::new("_encode");
+        hidden.push(stmt!["return ", &json, "(self.request)"]);
+
+        let mut class = ClassSpec::new("Client");
+        class.push_method(MethodKind::Instance, init);
+        class.push_method(MethodKind::Instance, get_int);
+        class.push_method(MethodKind::Instance, get_str);
+        class.push_method(MethodKind::Instance, hidden);
+        class.push(stmt!["timeout: ", &int, " = 30"]);
+
+        let mut file = FileSpec::new();
+        file.import_style(ImportStyle::From);
+        file.stub();
+        file.drop_private();
+        file.push(class);
+        file.push(FunctionSpec::new("_helper"));
+        file.push(stmt!["ENCODER = ", json]);
+
+        let mut main = IfSpec::new(stmt!["__name__ == ", Variable::String("__main__".into())]);
+        main.body().push(stmt![Name::imported("app", "main"), "()"]);
+        file.push(main);
+
+        assert_eq!(
+            "from typing import overload\n\n\
+             from requests import Request\n\n\
+             class Client:\n  \
+             def __init__(self, request: Request): ...\n\n  \
+             @overload\n  def get(self, key: int) -> int: ...\n\n  \
+             @overload\n  def get(self, key: str) -> str: ...\n\n  \
+             timeout: int = ...\n\n\
+             ENCODER = ...\n",
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn test_concat_imports() {
+        let path = Name::imported("os", "path");
+
+        let mut elements = Elements::new();
+        elements.push("print(");
+        elements.push(Concat(stmt![path, ".sep)"]));
+
+        let mut file = FileSpec::new();
+        file.push(elements);
+
+        assert_eq!("import os\n\nprint(os.path.sep)\n", file.to_string());
+    }
+
+    #[test]
+    fn test_visitors() {
+        use common::{Visitor, VisitorMut};
+
+        /// Import all names from a module with `import module`.
+        struct Qualify(&'static str);
+
+        impl VisitorMut<Variable> for Qualify {
+            fn visit_variable_mut(&mut self, variable: &mut Variable) {
+                if let Variable::Name(Name::Imported(ref mut imported)) = *variable {
+                    if imported.module == self.0 {
+                        imported.style = Some(ImportStyle::Module);
+                    }
+                }
+
+                walk_variable_mut(self, variable);
+            }
+        }
+
+        struct Collect(Vec<String>);
+
+        impl Visitor<Variable> for Collect {
+            fn visit_variable(&mut self, variable: &Variable) {
+                if let Variable::Name(Name::Imported(ref imported)) = *variable {
+                    self.0.push(imported.name.clone());
+                }
+
+                walk_variable(self, variable);
+            }
+        }
+
+        let path = Name::imported("os", "PathLike");
+        let sep = Name::imported("os", "sep");
+        let dumps = Name::imported("json", "dumps");
+
+        let mut argument = ArgumentSpec::new("target");
+        argument.annotation(Type::name(&path));
+
+        let mut dump = MethodSpec::new("dump");
+        dump.push_argument(argument);
+        dump.push(stmt!["return ", dumps, "(target) + ", sep]);
+
+        let mut class = ClassSpec::new("Dumper");
+        class.push_method(MethodKind::Static, dump);
+
+        let mut elements = Elements::new();
+        elements.push(class);
+
+        Qualify("os").visit_elements_mut(&mut elements);
+
+        let mut collect = Collect(Vec::new());
+        collect.visit_elements(&elements);
+        assert_eq!(vec!["PathLike", "dumps", "sep"], collect.0);
+
+        let mut file = FileSpec::new();
+        file.import_style(ImportStyle::From);
+        file.push(elements);
+
+        assert_eq!(
+            "import os\nfrom json import dumps\n\n\
+             class Dumper:\n  @staticmethod\n  def dump(target: os.PathLike):\n    \
+             return dumps(target) + os.sep\n",
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn test_decorator_arguments() {
+        let app = Name::imported("server", "app");
+        let handler = Name::imported("handlers", "Handler");
+
+        let mut route = DecoratorSpec::new(app);
+        route.push_attribute("route");
+        route.push_argument(Variable::String("/x".into()));
+        route.push_keyword("methods", stmt!["[", Variable::String("GET".into()), "]"]);
+        route.push_keyword("handler", stmt![handler]);
+
+        let mut fixture = DecoratorSpec::new(Name::imported("pytest", "fixture"));
+        fixture.call();
+
+        let mut index = MethodSpec::new("index");
+        index.push_decorator(route);
+        index.push_decorator(fixture);
+        index.push(stmt!["return 12"]);
+
+        let mut file = FileSpec::new();
+        file.push(index);
+
+        assert_eq!(
+            "import handlers\nimport pytest\nimport server\n\n\
+             @server.app.route(\"/x\", methods=[\"GET\"], handler=handlers.Handler)\n\
+             @pytest.fixture()\ndef index():\n  return 12\n",
+            file.to_string()
+        );
+    }
 }
 
 
@@ -99,6 +735,139 @@ mod java_tests {
         let class: Element = class.into();
         assert_eq!("public enum Foo {\n  ;\n}", class.to_string());
     }
+
+    #[test]
+    fn test_enum_constant_spec() {
+        let string_type = Type::class("java.lang", "String");
+        let deprecated = Type::class("java.lang", "Deprecated");
+
+        let mut to_string = MethodSpec::new(mods![Modifier::Public], "toString");
+        to_string.push_annotation(Type::class("java.lang", "Override"));
+        to_string.returns(&string_type);
+        to_string.push(stmt!["return ", Variable::String("first".to_owned()), ";"]);
+
+        let mut first = EnumConstantSpec::new("FIRST");
+        first.push_javadoc("The first constant.");
+        first.push_argument(stmt![Variable::String("a".to_owned())]);
+        first.push_argument("1");
+        first.push(to_string);
+
+        let mut second = EnumConstantSpec::new("SECOND");
+        second.push_annotation(deprecated);
+
+        let mut spec = EnumSpec::new(mods![Modifier::Public], "Foo");
+        spec.push_value(first);
+        spec.push_value(second);
+
+        let spec: Element = spec.into();
+
+        assert_eq!(
+            "public enum Foo {\n  /**\n   * The first constant.\n   */\n  FIRST(\"a\", 1) {\n    \
+             @Override\n    public String toString() {\n      return \"first\";\n    }\n  },\n  \
+             @Deprecated\n  SECOND;\n}",
+            spec.to_string()
+        );
+    }
+
+    #[test]
+    fn test_value_class_builder() {
+        let string_type = Type::class("java.lang", "String");
+        let list_type = Type::class("java.util", "List");
+        let list_of_strings = list_type.with_arguments(vec![&string_type]);
+
+        let mut value = ValueClassBuilder::new(mods![Modifier::Public, Modifier::Final], "Value");
+        value.push_field(FieldSpec::new(mods![], &list_of_strings, "values"));
+        value.push_field(FieldSpec::new(mods![], INTEGER, "count"));
+        value.jackson();
+        value.null_checks();
+
+        let mut file = FileSpec::new("se.tedro");
+        file.push(value);
+
+        let reference = ::std::str::from_utf8(include_bytes!("tests/Value.java")).unwrap();
+        assert_eq!(reference, file.to_string());
+    }
+
+    #[test]
+    fn test_javadoc_spec() {
+        let string_type = Type::class("java.lang", "String");
+        let map_type = Type::class("java.util", "Map");
+        let io_exception = Type::class("java.io", "IOException");
+
+        let name = ArgumentSpec::new(mods![Modifier::Final], &string_type, "name");
+        let other = ArgumentSpec::new(mods![Modifier::Final], &string_type, "other");
+
+        let mut javadoc = JavadocSpec::new();
+        javadoc.push(stmt!["Look up a name in a ", JavadocSpec::link(&map_type), "."]);
+        javadoc.param(&name, "name to look up");
+        javadoc.returns("the resolved value");
+        javadoc.throws(&io_exception, "if the lookup fails");
+
+        let mut lookup = MethodSpec::new(mods![Modifier::Public], "lookup");
+        lookup.javadoc(javadoc.clone());
+        lookup.push_argument(&name);
+        lookup.returns(&string_type);
+        lookup.throws(&io_exception);
+        lookup.push("return name;");
+
+        assert!(lookup.validate_javadoc().is_ok());
+
+        let mut class = ClassSpec::new(mods![Modifier::Public], "Lookup");
+        class.push(&lookup);
+
+        let mut file = FileSpec::new("se.tedro");
+        file.push(&class);
+
+        assert_eq!(
+            "package se.tedro;\n\nimport java.io.IOException;\nimport java.util.Map;\n\n\
+             public class Lookup {\n  /**\n   * Look up a name in a {@link Map}.\n   *\n   \
+             * @param name name to look up\n   * @return the resolved value\n   \
+             * @throws IOException if the lookup fails\n   */\n  \
+             public String lookup(final String name) throws IOException {\n    return name;\n  \
+             }\n}\n",
+            file.to_string()
+        );
+
+        let mut invalid = JavadocSpec::new();
+        invalid.param(&other, "not an argument");
+        lookup.javadoc(invalid);
+
+        assert!(lookup.validate_javadoc().is_err());
+    }
+
+    #[test]
+    fn test_java_project() {
+        let mut foo = ClassSpec::new(mods![Modifier::Public], "Foo");
+        foo.push(ClassSpec::new(mods![Modifier::Public], "Inner"));
+
+        let bar = InterfaceSpec::new(mods![Modifier::Public], "Bar");
+        let hidden = ClassSpec::new(mods![], "Hidden");
+
+        let mut foo_file = FileSpec::new("se.tedro");
+        foo_file.push(&foo);
+        foo_file.push(&hidden);
+
+        let mut bar_file = FileSpec::new("se.tedro.bar");
+        bar_file.push(&bar);
+
+        let mut project = JavaProject::new();
+        project.push(foo_file);
+        project.push(bar_file);
+
+        let files = project.to_map().unwrap();
+        let paths: Vec<_> = files.keys().map(|p| p.to_str().unwrap()).collect();
+
+        assert_eq!(
+            vec!["src/main/java/se/tedro/Foo.java", "src/main/java/se/tedro/bar/Bar.java"],
+            paths
+        );
+
+        let mut hidden_file = FileSpec::new("se.tedro");
+        hidden_file.push(&hidden);
+        project.push(hidden_file);
+
+        assert!(project.to_map().is_err());
+    }
 }
 
 #[cfg(test)]
@@ -121,4 +890,478 @@ mod js_tests {
             result
         );
     }
+
+    #[test]
+    fn test_exports() {
+        let mut foo = FunctionSpec::new("foo");
+        foo.export();
+        foo.push("return 42;");
+
+        let mut file = FileSpec::new();
+        file.push(foo);
+        file.push("const bar = 1;");
+        file.push_export(ExportSpec::named("bar"));
+        file.push_export(ExportSpec::named_alias("bar", "baz"));
+        file.push_export(ExportSpec::named("bar"));
+        file.push_export(ExportSpec::default_export("bar"));
+        file.push_export(ExportSpec::re_export("util", "a"));
+        file.push_export(ExportSpec::re_export_alias("util", "b", "c"));
+        file.push_export(ExportSpec::re_export_all("other"));
+        file.push_export(ExportSpec::re_export_all_alias("other", "ns"));
+
+        assert_eq!(
+            "export function foo() {\n  return 42;\n}\n\nconst bar = 1;\n\n\
+             export {bar, bar as baz};\nexport {a, b as c} from \"util.js\";\n\
+             export * from \"other.js\";\nexport * as ns from \"other.js\";\n\
+             export default bar;\n",
+            file.to_string()
+        );
+
+        file.push_export(ExportSpec::named_alias("foo", "baz"));
+        assert!(file.format(&mut String::new()).is_err());
+
+        let mut inline = FunctionSpec::new("foo");
+        inline.export();
+
+        let mut file = FileSpec::new();
+        file.push(inline);
+        file.push_export(ExportSpec::named("foo"));
+        assert!(file.format(&mut String::new()).is_err());
+
+        file.module_system(ModuleSystem::CommonJs);
+        assert!(file.format(&mut String::new()).is_err());
+    }
+
+    #[test]
+    fn test_module_resolvers() {
+        let relative = RelativeResolver::new("app.views.main", Extension::None);
+        assert_eq!("./util", relative.resolve("app.views.util"));
+        assert_eq!("../models/user", relative.resolve("app.models.user"));
+        assert_eq!("../../config", relative.resolve("config"));
+        assert_eq!("@org/pkg", relative.resolve("@org/pkg"));
+
+        let path = PathResolver::default();
+        assert_eq!("app/util.js", path.resolve("app.util"));
+        assert_eq!("./polyfill", path.resolve("./polyfill"));
+        assert_eq!("../shared/util", path.resolve("../shared/util"));
+        assert_eq!("@org/pkg", path.resolve("@org/pkg"));
+
+        let mut packages = PackageResolver::new(PathResolver::new(Extension::Custom("mjs".into())));
+        packages.push_package("lodash");
+        assert_eq!("lodash", packages.resolve("lodash"));
+        assert_eq!("lodash/fp", packages.resolve("lodash/fp"));
+        assert_eq!("@org/pkg", packages.resolve("@org/pkg"));
+        assert_eq!("./polyfill", packages.resolve("./polyfill"));
+        assert_eq!("app/util.mjs", packages.resolve("app.util"));
+
+        let mut foo = FunctionSpec::new("foo");
+        foo.push(stmt!["return ", Name::imported("lodash", "map"), "();"]);
+
+        let mut file = FileSpec::new();
+        file.resolver(packages);
+        file.push(foo);
+
+        assert_eq!(
+            "import {map} from \"lodash\";\n\nfunction foo() {\n  return map();\n}\n",
+            file.to_string()
+        );
+
+        let mut file = FileSpec::new();
+        file.push_import(Name::side_effect("./polyfill"));
+        file.push(stmt![Name::imported("@org/pkg", "x"), "();"]);
+
+        assert_eq!(
+            "import \"./polyfill\";\nimport {x} from \"@org/pkg\";\n\nx();\n",
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn test_import_kinds() {
+        let react = Name::imported_default("react", "React");
+        let use_state = Name::imported("react", "useState");
+        let ns = Name::imported_alias("react", "version", "R");
+        let join = Name::imported_as("path", "join", "joinPath");
+
+        let mut foo = FunctionSpec::new("foo");
+        foo.push(stmt![use_state, "(", ns, ");"]);
+        foo.push(stmt!["return ", react, ".render(", join, "());"]);
+
+        let mut file = FileSpec::new();
+        file.resolver(BareResolver);
+        file.push_import(Name::side_effect("./polyfill"));
+        file.push_import(Name::side_effect("react"));
+        file.push(foo);
+
+        assert_eq!(
+            "import \"./polyfill\";\nimport {join as joinPath} from \"path\";\n\
+             import React, * as R from \"react\";\nimport {useState} from \"react\";\n\n\
+             function foo() {\n  useState(R.version);\n\n  return React.render(joinPath());\n}\n",
+            file.to_string()
+        );
+
+        file.push_import(Name::imported("other", "useState"));
+        assert!(file.format(&mut String::new()).is_err());
+
+        let mut file = FileSpec::new();
+        file.resolver(BareResolver);
+        file.push_import(Name::side_effect("zone.js"));
+        file.push_import(Name::side_effect("core-js"));
+        file.push(stmt![Name::imported("angular", "x"), "();"]);
+
+        assert_eq!(
+            "import \"zone.js\";\nimport \"core-js\";\nimport {x} from \"angular\";\n\nx();\n",
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn test_class_members() {
+        let mut class = ClassSpec::new("Counter");
+        class.extends(Name::imported("base", "Base"));
+
+        let mut count = FieldSpec::with_static("count");
+        count.private();
+        count.initializer("0");
+        class.push_field(count);
+        class.push_field(FieldSpec::new("name"));
+
+        let mut value = MethodSpec::getter("value");
+        value.push("return Counter.#count;");
+        class.push(value);
+
+        let mut set_value = MethodSpec::setter("value");
+        set_value.push_argument("value");
+        set_value.push("Counter.#count = value;");
+        class.push(set_value);
+
+        let mut fetch = MethodSpec::with_static("fetch");
+        fetch.asynchronous();
+        fetch.generator();
+        fetch.push("yield 1;");
+        class.push(fetch);
+
+        let mut load = FunctionSpec::new("load");
+        load.asynchronous();
+        load.push("return 1;");
+
+        let mut file = FileSpec::new();
+        file.push(class);
+        file.push(load);
+
+        assert_eq!(
+            "import {Base} from \"base.js\";\n\n\
+             class Counter extends Base {\n  static #count = 0;\n  name;\n\n  \
+             get value() {\n    return Counter.#count;\n  }\n\n  \
+             set value(value) {\n    Counter.#count = value;\n  }\n\n  \
+             static async *fetch() {\n    yield 1;\n  }\n}\n\n\
+             async function load() {\n  return 1;\n}\n",
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn test_object_literals() {
+        let symbol = Name::imported("symbols", "key");
+
+        let mut list = ArraySpec::new();
+        list.push("1");
+        list.push_spread("rest");
+
+        let mut inner = ObjectSpec::new();
+        inner.push("enabled", "true");
+
+        let mut object = ObjectSpec::new();
+        object.push("name", Variable::String("foo".into()));
+        object.push("content-type", Variable::String("text/plain".into()));
+        object.push("0", "zero");
+        object.push("01", "one");
+        object.push_computed(stmt![symbol], "42");
+        object.push_shorthand("list");
+        object.push_spread("defaults");
+
+        let mut compact = object.clone();
+        compact.width(100);
+
+        let mut file = FileSpec::new();
+        file.push(stmt!["const a = ", compact, ";"]);
+
+        object.push("list", list);
+        object.push("inner", inner);
+        file.push(stmt!["const b = ", object, ";"]);
+
+        assert_eq!(
+            "import {key} from \"symbols.js\";\n\n\
+             const a = {name: \"foo\", \"content-type\": \"text/plain\", 0: zero, \"01\": one, \
+             [key]: 42, list, ...defaults};\n\n\
+             const b = {\n  name: \"foo\",\n  \"content-type\": \"text/plain\",\n  0: zero,\n  \
+             \"01\": one,\n  [key]: 42,\n  list,\n  ...defaults,\n  list: [1, ...rest],\n  \
+             inner: {enabled: true},\n};\n",
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn test_visitors() {
+        use common::Visitor;
+
+        struct Collect(Vec<String>);
+
+        impl Visitor<Variable> for Collect {
+            fn visit_variable(&mut self, variable: &Variable) {
+                if let Variable::Name(Name::Imported(ref imported)) = *variable {
+                    self.0.push(imported.name.clone());
+                }
+
+                walk_variable(self, variable);
+            }
+        }
+
+        let mut arrow = ArrowFunctionSpec::expression(stmt![Name::imported("util", "format")]);
+        arrow.push_argument("value");
+
+        let mut object = ObjectSpec::new();
+        object.push("format", arrow);
+        object.push("items", stmt![Variable::Template(vec![
+            "x".into(),
+            TemplatePart::Expression(stmt![Name::imported("util", "items")]),
+        ])]);
+
+        let mut collect = Collect(Vec::new());
+        collect.visit_statement(&stmt!["const a = ", object, ";"]);
+        assert_eq!(vec!["format", "items"], collect.0);
+    }
+
+    #[test]
+    fn test_declarations() {
+        let defaults = Name::imported("config", "DEFAULTS");
+        let fetch = Name::imported("http", "fetch");
+
+        let mut options = ObjectPattern::new();
+        options.push("url");
+        options.push_default("retries", stmt![defaults]);
+        options.push_target("headers", "h");
+        options.rest("other");
+
+        let mut pair = ArrayPattern::new();
+        pair.push("first");
+        pair.push_hole();
+        pair.push_default("third", "3");
+        pair.rest("tail");
+
+        let mut run = ArrowFunctionSpec::block();
+        run.asynchronous();
+        run.push_argument(ParameterSpec::new(options));
+
+        let mut retry = ParameterSpec::new("retry");
+        retry.default("true");
+        run.push_argument(retry);
+        run.push_argument(ParameterSpec::rest("args"));
+        run.push(stmt!["return await ", fetch, "(url);"]);
+
+        let mut wrap = ObjectSpec::new();
+        wrap.push_shorthand("value");
+
+        let mut wrapper = ArrowFunctionSpec::expression(wrap);
+        wrapper.push_argument("value");
+
+        let mut counter = VariableDeclSpec::with_let("counter");
+        counter.initializer("0");
+
+        let mut file = FileSpec::new();
+        file.push(VariableDeclSpec::constant("run", run));
+        file.push(VariableDeclSpec::constant(pair, "list"));
+        file.push(VariableDeclSpec::constant("wrap", wrapper));
+        file.push(counter);
+        file.push(VariableDeclSpec::with_let("unset"));
+
+        assert_eq!(
+            "import {DEFAULTS} from \"config.js\";\nimport {fetch} from \"http.js\";\n\n\
+             const run = async ({url, retries = DEFAULTS, headers: h, ...other}, retry = true, \
+             ...args) => {\n  return await fetch(url);\n};\n\n\
+             const [first, , third = 3, ...tail] = list;\n\n\
+             const wrap = (value) => ({value});\n\n\
+             let counter = 0;\n\n\
+             let unset;\n",
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        let name = Name::imported("names", "name");
+
+        let template = Variable::Template(vec![
+            "`${a}` $ \\ \n ".into(),
+            TemplatePart::Expression(stmt![name]),
+            "\u{0008}".into(),
+        ]);
+
+        let mut file = FileSpec::new();
+        file.push(stmt![
+            "const a = ",
+            Variable::String("\u{0008}\u{000c}\u{000b}\u{0007}\u{0014}\0'\"\u{2028}é😀".into()),
+            ";",
+        ]);
+        file.push(stmt!["const b = ", template, ";"]);
+
+        assert_eq!(
+            "import {name} from \"names.js\";\n\n\
+             const a = \"\\b\\f\\v\\x07\\x14\\x00'\\\"\\u2028é😀\";\n\n\
+             const b = `\\`\\${a}\\` $ \\\\ \\n ${name}\\b`;\n",
+            file.to_string()
+        );
+
+        file.ascii_only();
+
+        assert_eq!(
+            "import {name} from \"names.js\";\n\n\
+             const a = \"\\b\\f\\v\\x07\\x14\\x00'\\\"\\u2028\\xe9\\ud83d\\ude00\";\n\n\
+             const b = `\\`\\${a}\\` $ \\\\ \\n ${name}\\b`;\n",
+            file.to_string()
+        );
+
+        let split = Variable::Template(vec!["$".into(), "{x}".into()]);
+        assert_eq!("`\\${x}`", Element::from(stmt![split]).to_string());
+    }
+
+    #[test]
+    fn test_common_js() {
+        let hello = Name::imported("foo", "hello");
+        let bar = Name::imported_default("bar", "bar");
+
+        let mut foo = FunctionSpec::new("foo");
+        foo.export();
+        foo.push(stmt!["return ", hello, "(", bar, ");"]);
+
+        let mut file = FileSpec::new();
+        file.module_system(ModuleSystem::CommonJs);
+        file.push_import(Name::side_effect("polyfill"));
+        file.push(foo);
+        file.push_export(ExportSpec::named_alias("foo", "baz"));
+        file.push_export(ExportSpec::re_export("util", "a"));
+        file.push_export(ExportSpec::re_export_all("other"));
+
+        assert_eq!(
+            "require(\"polyfill.js\");\n\
+             const bar = require(\"bar.js\");\nconst {hello} = require(\"foo.js\");\n\nfunction foo() {\n  return hello(bar);\n}\n\n\
+             exports.foo = foo;\nexports.baz = foo;\nexports.a = require(\"util.js\").a;\n\
+             Object.assign(exports, require(\"other.js\"));\n",
+            file.to_string()
+        );
+
+        file.push_export(ExportSpec::default_export("foo"));
+
+        assert_eq!(
+            "require(\"polyfill.js\");\n\
+             const bar = require(\"bar.js\");\nconst {hello} = require(\"foo.js\");\n\nfunction foo() {\n  return hello(bar);\n}\n\n\
+             module.exports = foo;\nmodule.exports.foo = foo;\nmodule.exports.baz = foo;\n\
+             module.exports.a = require(\"util.js\").a;\n\
+             Object.assign(module.exports, require(\"other.js\"));\n",
+            file.to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+mod typescript_tests {
+    use typescript::*;
+
+    #[test]
+    fn test_file() {
+        let user = Name::imported("models.user", "User");
+        let client = Name::imported("client", "Client");
+        let config = Name::imported_default("config", "Config");
+
+        let mut id = TypeAliasSpec::new(
+            "Id",
+            Type::union(vec![Type::built_in("string"), Type::built_in("number")]),
+        );
+        id.export();
+
+        let mut color = EnumSpec::new("Color");
+        color.with_const();
+        color.push_member_value("Red", "1");
+        color.push_member("Green");
+
+        let mut repository = InterfaceSpec::new("Repository");
+        repository.export();
+        repository.push_type_parameter("T");
+
+        let mut name = PropertySpec::new("name", Type::built_in("string"));
+        name.readonly();
+        name.optional();
+        repository.push_property(name);
+
+        let mut find = MethodSpec::new("find");
+        find.push_parameter(ParameterSpec::with_type("id", Name::local("Id")));
+        find.returns(Type::generic(
+            Type::built_in("Promise"),
+            vec![Type::union(vec![Type::name(&user), Type::literal("null")])],
+        ));
+        repository.push_method(find);
+
+        let mut key = TypeParameter::new("K");
+        key.constraint(Type::union(vec![
+            Type::string_literal("a"),
+            Type::string_literal("b"),
+        ]));
+
+        let mut lookup = FunctionSpec::new("lookup");
+        lookup.push_type_parameter(key);
+        lookup.push_parameter(ParameterSpec::with_type("key", Name::local("K")));
+
+        let mut config_param = ParameterSpec::with_type("config", &config);
+        config_param.optional();
+        lookup.push_parameter(config_param);
+
+        lookup.returns(Type::array(Type::intersection(vec![
+            Type::name(&user),
+            Type::union(vec![Type::built_in("A"), Type::built_in("B")]),
+        ])));
+        lookup.push(stmt!["return new ", client, "().lookup(key);"]);
+
+        let mut file = FileSpec::new();
+        file.push(id);
+        file.push(color);
+        file.push(repository);
+        file.push(lookup);
+
+        assert_eq!(
+            "import {Client} from \"client\";\n\
+             import type Config from \"config\";\n\
+             import type {User} from \"models/user\";\n\n\
+             export type Id = string | number;\n\n\
+             const enum Color {\n  Red = 1,\n  Green,\n}\n\n\
+             export interface Repository<T> {\n  readonly name?: string;\n  \
+             find(id: Id): Promise<User | null>;\n}\n\n\
+             function lookup<K extends \"a\" | \"b\">(key: K, config?: Config): (User & (A | B))[] {\n  \
+             return new Client().lookup(key);\n}\n",
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn test_class_extends() {
+        let base = Name::imported("base", "Base");
+        let user = Name::imported("models.user", "User");
+        let serializable = Name::imported("serial", "Serializable");
+
+        let mut class = ClassSpec::new("Users");
+        class.export();
+        class.extends_generic(base, vec![&user]);
+        class.implements(serializable);
+        class.push_property(PropertySpec::new("name", Type::built_in("string")));
+
+        let mut file = FileSpec::new();
+        file.push(class);
+
+        assert_eq!(
+            "import {Base} from \"base\";\n\
+             import type {User} from \"models/user\";\n\
+             import type {Serializable} from \"serial\";\n\n\
+             export class Users extends Base<User> implements Serializable {\n  name: string;\n}\n",
+            file.to_string()
+        );
+    }
 }
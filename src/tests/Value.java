@@ -0,0 +1,76 @@
+package se.tedro;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.List;
+import java.util.Objects;
+
+public final class Value {
+  private final List<String> values;
+  private final int count;
+
+  @JsonCreator
+  public Value(
+    @JsonProperty("values") final List<String> values, @JsonProperty("count") final int count
+  ) {
+    this.values = Objects.requireNonNull(values, "values");
+    this.count = count;
+  }
+
+  @JsonProperty("values")
+  public List<String> getValues() {
+    return this.values;
+  }
+
+  @JsonProperty("count")
+  public int getCount() {
+    return this.count;
+  }
+
+  @Override
+  public boolean equals(final Object other) {
+    if (this == other) {
+      return true;
+    }
+
+    if (!(other instanceof Value)) {
+      return false;
+    }
+
+    final Value o = (Value) other;
+    return Objects.equals(this.values, o.values) && this.count == o.count;
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(this.values, this.count);
+  }
+
+  @Override
+  public String toString() {
+    return "Value(values=" + this.values + ", count=" + this.count + ")";
+  }
+
+  public static Builder builder() {
+    return new Builder();
+  }
+
+  public static class Builder {
+    private List<String> values;
+    private int count;
+
+    public Builder values(final List<String> values) {
+      this.values = values;
+      return this;
+    }
+
+    public Builder count(final int count) {
+      this.count = count;
+      return this;
+    }
+
+    public Value build() {
+      return new Value(this.values, this.count);
+    }
+  }
+}
@@ -0,0 +1,185 @@
+use super::*;
+use codeviz_common::ElementFormat;
+use codeviz_js::quote_string;
+
+/// TypeScript types.
+#[derive(Debug, Clone)]
+pub enum Type {
+    /// A named type, like `string` or an imported `Foo`.
+    Name(Name),
+    /// A generic type and its arguments, like `Map<string, number>`.
+    Generic(Box<Type>, Vec<Type>),
+    /// An array type, like `string[]`.
+    Array(Box<Type>),
+    /// A union type, like `string | number`.
+    Union(Vec<Type>),
+    /// An intersection type, like `A & B`.
+    Intersection(Vec<Type>),
+    /// A string literal type, like `"foo"`.
+    StringLiteral(String),
+    /// Any other literal type, like `42` or `true`.
+    Literal(String),
+}
+
+impl Type {
+    pub fn name<N>(name: N) -> Type
+    where
+        N: Into<Name>,
+    {
+        Type::Name(name.into())
+    }
+
+    pub fn built_in(name: &str) -> Type {
+        Type::Name(Name::built_in(name).into())
+    }
+
+    pub fn generic<B, A>(base: B, arguments: Vec<A>) -> Type
+    where
+        B: Into<Type>,
+        A: Into<Type>,
+    {
+        let arguments = arguments.into_iter().map(Into::into).collect();
+        Type::Generic(Box::new(base.into()), arguments)
+    }
+
+    pub fn array<T>(inner: T) -> Type
+    where
+        T: Into<Type>,
+    {
+        Type::Array(Box::new(inner.into()))
+    }
+
+    pub fn union<T>(types: Vec<T>) -> Type
+    where
+        T: Into<Type>,
+    {
+        Type::Union(types.into_iter().map(Into::into).collect())
+    }
+
+    pub fn intersection<T>(types: Vec<T>) -> Type
+    where
+        T: Into<Type>,
+    {
+        Type::Intersection(types.into_iter().map(Into::into).collect())
+    }
+
+    pub fn string_literal(value: &str) -> Type {
+        Type::StringLiteral(value.to_owned())
+    }
+
+    pub fn literal(value: &str) -> Type {
+        Type::Literal(value.to_owned())
+    }
+
+    pub fn format<E>(&self, out: &mut E) -> Result<()>
+    where
+        E: ElementFormat,
+    {
+        match *self {
+            Type::Name(ref name) => name.format(out)?,
+            Type::Generic(ref base, ref arguments) => {
+                base.format(out)?;
+                out.write_char('<')?;
+                format_joined(out, arguments, ", ", false)?;
+                out.write_char('>')?;
+            }
+            Type::Array(ref inner) => {
+                format_grouped(out, inner, inner.is_compound())?;
+                out.write_str("[]")?;
+            }
+            Type::Union(ref types) => format_joined(out, types, " | ", false)?,
+            Type::Intersection(ref types) => format_joined(out, types, " & ", true)?,
+            Type::StringLiteral(ref value) => quote_string(out, value)?,
+            Type::Literal(ref value) => out.write_str(value)?,
+        }
+
+        Ok(())
+    }
+
+    /// If this type is made up of operators which bind looser than array and intersection
+    /// types.
+    fn is_compound(&self) -> bool {
+        match *self {
+            Type::Union(ref types) | Type::Intersection(ref types) => types.len() > 1,
+            _ => false,
+        }
+    }
+
+    fn is_union(&self) -> bool {
+        match *self {
+            Type::Union(ref types) => types.len() > 1,
+            _ => false,
+        }
+    }
+}
+
+fn format_grouped<E>(out: &mut E, ty: &Type, grouped: bool) -> Result<()>
+where
+    E: ElementFormat,
+{
+    if grouped {
+        out.write_char('(')?;
+        ty.format(out)?;
+        out.write_char(')')?;
+    } else {
+        ty.format(out)?;
+    }
+
+    Ok(())
+}
+
+fn format_joined<E>(out: &mut E, types: &[Type], separator: &str, group_unions: bool) -> Result<()>
+where
+    E: ElementFormat,
+{
+    let mut it = types.iter().peekable();
+
+    while let Some(ty) = it.next() {
+        format_grouped(out, ty, group_unions && ty.is_union())?;
+
+        if it.peek().is_some() {
+            out.write_str(separator)?;
+        }
+    }
+
+    Ok(())
+}
+
+impl<'a, T> From<&'a T> for Type
+where
+    T: Into<Type> + Clone,
+{
+    fn from(value: &'a T) -> Type {
+        value.clone().into()
+    }
+}
+
+impl From<Name> for Type {
+    fn from(value: Name) -> Type {
+        Type::Name(value)
+    }
+}
+
+impl From<ImportedName> for Type {
+    fn from(value: ImportedName) -> Type {
+        Type::Name(value.into())
+    }
+}
+
+impl From<BuiltInName> for Type {
+    fn from(value: BuiltInName) -> Type {
+        Type::Name(value.into())
+    }
+}
+
+impl From<LocalName> for Type {
+    fn from(value: LocalName) -> Type {
+        Type::Name(value.into())
+    }
+}
+
+impl From<Type> for Variable {
+    fn from(value: Type) -> Variable {
+        Variable::Type(value)
+    }
+}
@@ -0,0 +1,147 @@
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct ClassSpec {
+    pub name: String,
+    pub type_parameters: Vec<TypeParameter>,
+    /// The class being extended, which is referenced as a value.
+    pub extends: Option<Name>,
+    /// Type arguments of the class being extended.
+    pub extends_arguments: Vec<Type>,
+    pub implements: Vec<Type>,
+    pub properties: Vec<PropertySpec>,
+    pub constructors: Elements,
+    pub elements: Elements,
+    pub export: bool,
+}
+
+impl ClassSpec {
+    pub fn new(name: &str) -> ClassSpec {
+        ClassSpec {
+            name: name.to_owned(),
+            type_parameters: Vec::new(),
+            extends: None,
+            extends_arguments: Vec::new(),
+            implements: Vec::new(),
+            properties: Vec::new(),
+            constructors: Elements::new(),
+            elements: Elements::new(),
+            export: false,
+        }
+    }
+
+    pub fn export(&mut self) {
+        self.export = true;
+    }
+
+    pub fn push_type_parameter<T>(&mut self, parameter: T)
+    where
+        T: Into<TypeParameter>,
+    {
+        self.type_parameters.push(parameter.into());
+    }
+
+    pub fn extends<N>(&mut self, extends: N)
+    where
+        N: Into<Name>,
+    {
+        self.extends = Some(extends.into());
+        self.extends_arguments.clear();
+    }
+
+    pub fn extends_generic<N, A>(&mut self, extends: N, arguments: Vec<A>)
+    where
+        N: Into<Name>,
+        A: Into<Type>,
+    {
+        self.extends = Some(extends.into());
+        self.extends_arguments = arguments.into_iter().map(Into::into).collect();
+    }
+
+    pub fn implements<T>(&mut self, implements: T)
+    where
+        T: Into<Type>,
+    {
+        self.implements.push(implements.into());
+    }
+
+    pub fn push_property<P>(&mut self, property: P)
+    where
+        P: Into<PropertySpec>,
+    {
+        self.properties.push(property.into());
+    }
+
+    pub fn push_constructor<E>(&mut self, element: E)
+    where
+        E: Into<Element>,
+    {
+        self.constructors.push(element);
+    }
+
+    pub fn push<E>(&mut self, element: E)
+    where
+        E: Into<Element>,
+    {
+        self.elements.push(element);
+    }
+}
+
+impl From<ClassSpec> for Element {
+    fn from(value: ClassSpec) -> Element {
+        let mut open = Statement::new();
+
+        if value.export {
+            open.push("export ");
+        }
+
+        open.push("class ");
+        open.push(value.name);
+        open.push(type_parameters(value.type_parameters));
+
+        if let Some(extends) = value.extends {
+            open.push(" extends ");
+            open.push(extends);
+
+            if !value.extends_arguments.is_empty() {
+                open.push("<");
+                open.push(join_types(value.extends_arguments, ", "));
+                open.push(">");
+            }
+        }
+
+        if !value.implements.is_empty() {
+            open.push(" implements ");
+            open.push(join_types(value.implements, ", "));
+        }
+
+        open.push(" {");
+
+        let mut body = Elements::new();
+
+        if !value.properties.is_empty() {
+            let mut properties = Elements::new();
+
+            for property in value.properties {
+                properties.push(property);
+            }
+
+            body.push(properties);
+        }
+
+        if !value.constructors.is_empty() {
+            body.push(value.constructors.join(Spacing));
+        }
+
+        if !value.elements.is_empty() {
+            body.push(value.elements.join(Spacing));
+        }
+
+        let mut out = Elements::new();
+        out.push(open);
+        out.push_nested(body.join(Spacing));
+        out.push("}");
+
+        out.into()
+    }
+}
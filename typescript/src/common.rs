@@ -0,0 +1,55 @@
+use super::*;
+
+/// Build the type parameters of a declaration, like `<K, V>`.
+///
+/// Builds an empty statement if there are no type parameters.
+pub fn type_parameters(parameters: Vec<TypeParameter>) -> Statement {
+    let mut out = Statement::new();
+
+    if parameters.is_empty() {
+        return out;
+    }
+
+    let mut arguments = Statement::new();
+
+    for parameter in parameters {
+        arguments.push(Statement::from(parameter));
+    }
+
+    out.push("<");
+    out.push(arguments.join(", "));
+    out.push(">");
+    out
+}
+
+/// Build a parenthesized parameter list, with an optional return type.
+pub fn signature(parameters: Vec<ParameterSpec>, returns: Option<Type>) -> Statement {
+    let mut arguments = Statement::new();
+
+    for parameter in parameters {
+        arguments.push(Statement::from(parameter));
+    }
+
+    let mut out = Statement::new();
+    out.push("(");
+    out.push(arguments.join(", "));
+    out.push(")");
+
+    if let Some(returns) = returns {
+        out.push(": ");
+        out.push(returns);
+    }
+
+    out
+}
+
+/// Join types with a separator, like the `implements` clause of a class.
+pub fn join_types(types: Vec<Type>, separator: &str) -> Statement {
+    let mut out = Statement::new();
+
+    for ty in types {
+        out.push(ty);
+    }
+
+    out.join(separator)
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct ConstructorSpec {
+    pub parameters: Vec<ParameterSpec>,
+    pub elements: Elements,
+}
+
+impl ConstructorSpec {
+    pub fn new() -> ConstructorSpec {
+        ConstructorSpec {
+            parameters: Vec::new(),
+            elements: Elements::new(),
+        }
+    }
+
+    pub fn push_parameter<P>(&mut self, parameter: P)
+    where
+        P: Into<ParameterSpec>,
+    {
+        self.parameters.push(parameter.into());
+    }
+
+    pub fn push<E>(&mut self, element: E)
+    where
+        E: Into<Element>,
+    {
+        self.elements.push(element);
+    }
+}
+
+impl From<ConstructorSpec> for Element {
+    fn from(value: ConstructorSpec) -> Element {
+        let mut open = Statement::new();
+        open.push("constructor");
+        open.push(signature(value.parameters, None));
+        open.push(" {");
+
+        let mut out = Elements::new();
+        out.push(open);
+        out.push_nested(value.elements.join(Spacing));
+        out.push("}");
+
+        out.into()
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+/// An enum declaration, like `enum Color { Red = 1, Green }`.
+#[derive(Debug, Clone)]
+pub struct EnumSpec {
+    pub name: String,
+    pub members: Vec<(String, Option<Statement>)>,
+    pub is_const: bool,
+    pub export: bool,
+}
+
+impl EnumSpec {
+    pub fn new(name: &str) -> EnumSpec {
+        EnumSpec {
+            name: name.to_owned(),
+            members: Vec::new(),
+            is_const: false,
+            export: false,
+        }
+    }
+
+    pub fn export(&mut self) {
+        self.export = true;
+    }
+
+    /// Declare the enum as `const enum`, which is inlined at its use sites.
+    pub fn with_const(&mut self) {
+        self.is_const = true;
+    }
+
+    pub fn push_member(&mut self, name: &str) {
+        self.members.push((name.to_owned(), None));
+    }
+
+    pub fn push_member_value<S>(&mut self, name: &str, value: S)
+    where
+        S: Into<Statement>,
+    {
+        self.members.push((name.to_owned(), Some(value.into())));
+    }
+}
+
+impl From<EnumSpec> for Element {
+    fn from(value: EnumSpec) -> Element {
+        let mut open = Statement::new();
+
+        if value.export {
+            open.push("export ");
+        }
+
+        if value.is_const {
+            open.push("const ");
+        }
+
+        open.push("enum ");
+        open.push(value.name);
+        open.push(" {");
+
+        let mut body = Elements::new();
+
+        for (name, initializer) in value.members {
+            let mut member = Statement::new();
+            member.push(name);
+
+            if let Some(initializer) = initializer {
+                member.push(" = ");
+                member.push(initializer);
+            }
+
+            member.push(",");
+            body.push(member);
+        }
+
+        let mut out = Elements::new();
+        out.push(open);
+        out.push_nested(body);
+        out.push("}");
+
+        out.into()
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::BTreeSet;
+use std::rc::Rc;
+use super::*;
+use codeviz_common::ElementFormatter;
+
+#[derive(Debug, Clone)]
+pub struct FileSpec {
+    pub elements: Elements,
+    pub imports: Vec<ImportedName>,
+    pub resolver: Rc<dyn ModuleResolver>,
+}
+
+impl FileSpec {
+    pub fn new() -> FileSpec {
+        FileSpec {
+            elements: Elements::new(),
+            imports: Vec::new(),
+            // TypeScript resolves modules without their extension.
+            resolver: Rc::new(PathResolver::new(Extension::None)),
+        }
+    }
+
+    /// Set the resolver used to turn module names into import specifiers.
+    pub fn resolver<R>(&mut self, resolver: R)
+    where
+        R: ModuleResolver + 'static,
+    {
+        self.resolver = Rc::new(resolver);
+    }
+
+    /// Import a name, even if it isn't used by any element.
+    pub fn push_import(&mut self, import: ImportedName) {
+        self.imports.push(import);
+    }
+
+    pub fn push<E>(&mut self, element: E)
+    where
+        E: Into<Element>,
+    {
+        self.elements.push(element);
+    }
+
+    fn imports(&self) -> Result<Option<Elements>> {
+        let mut imports = FileImports::default();
+        self.elements.imports(&mut imports);
+        imports.values.extend(self.imports.iter().cloned());
+
+        // names which are referenced as values need a regular import.
+        let types: BTreeSet<ImportedName> = imports
+            .types
+            .difference(&imports.values)
+            .cloned()
+            .collect();
+
+        // check for collisions across both kinds of imports.
+        ModuleImports::group(imports.values.iter().chain(types.iter()))?;
+
+        let values = ModuleImports::group(&imports.values)?;
+        let types = ModuleImports::group(&types)?;
+
+        let modules: BTreeSet<&String> = values.keys().chain(types.keys()).collect();
+
+        if modules.is_empty() {
+            return Ok(None);
+        }
+
+        let mut out = Elements::new();
+
+        for module in modules {
+            let path = self.resolver.resolve(module);
+
+            if let Some(imports) = values.get(module) {
+                let clauses = imports.clauses();
+
+                if clauses.is_empty() {
+                    out.push(import_statement("import ", None, &path));
+                }
+
+                for clause in clauses {
+                    out.push(import_statement("import ", Some(clause), &path));
+                }
+            }
+
+            if let Some(imports) = types.get(module) {
+                for clause in type_clauses(imports) {
+                    out.push(import_statement("import type ", Some(clause), &path));
+                }
+            }
+        }
+
+        Ok(Some(out))
+    }
+
+    pub fn format<W>(&self, out: &mut W) -> Result<()>
+    where
+        W: ::std::fmt::Write,
+    {
+        let mut elements = Elements::new();
+
+        if let Some(imports) = self.imports()? {
+            elements.push(imports);
+        }
+
+        elements.push(self.elements.clone().join(Spacing));
+
+        let elements: Element = elements.join(Spacing).into();
+
+        let mut extra = ();
+        elements.format(&mut ElementFormatter::new(out), &mut extra)?;
+        out.write_char('\n')?;
+
+        Ok(())
+    }
+}
+
+/// Names imported by a file, split by how they are referenced.
+#[derive(Debug, Default)]
+struct FileImports {
+    values: BTreeSet<ImportedName>,
+    types: BTreeSet<ImportedName>,
+}
+
+impl ImportReceiver for FileImports {
+    fn receive(&mut self, name: &ImportedName) {
+        self.values.insert(name.clone());
+    }
+
+    fn receive_type(&mut self, name: &ImportedName) {
+        self.types.insert(name.clone());
+    }
+}
+
+impl ImportReceiver for BTreeSet<ImportedName> {
+    fn receive(&mut self, name: &ImportedName) {
+        self.insert(name.clone());
+    }
+}
+
+fn import_statement(keyword: &str, clause: Option<String>, path: &str) -> Statement {
+    let mut stmt = Statement::new();
+    stmt.push(keyword);
+
+    if let Some(clause) = clause {
+        stmt.push(clause);
+        stmt.push(" from ");
+    }
+
+    stmt.push(Variable::String(path.to_owned()));
+    stmt.push(";");
+    stmt
+}
+
+/// Build the clauses of `import type` statements for a module.
+///
+/// Unlike regular imports, a type-only import can't combine a default import with other
+/// bindings.
+fn type_clauses(imports: &ModuleImports) -> Vec<String> {
+    let mut out: Vec<String> = imports.defaults.iter().cloned().collect();
+
+    for namespace in &imports.namespaces {
+        out.push(format!("* as {}", namespace));
+    }
+
+    if !imports.named.is_empty() {
+        let named: Vec<String> = imports
+            .named
+            .iter()
+            .map(|(name, local)| match *local {
+                Some(ref local) => format!("{} as {}", name, local),
+                None => name.clone(),
+            })
+            .collect();
+
+        out.push(format!("{{{}}}", named.join(", ")));
+    }
+
+    out
+}
+
+impl ToString for FileSpec {
+    fn to_string(&self) -> String {
+        let mut s = String::new();
+        self.format(&mut s).unwrap();
+        s
+    }
+}
@@ -0,0 +1,79 @@
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct FunctionSpec {
+    pub name: String,
+    pub type_parameters: Vec<TypeParameter>,
+    pub parameters: Vec<ParameterSpec>,
+    pub returns: Option<Type>,
+    pub elements: Elements,
+    pub export: bool,
+}
+
+impl FunctionSpec {
+    pub fn new(name: &str) -> FunctionSpec {
+        FunctionSpec {
+            name: name.to_owned(),
+            type_parameters: Vec::new(),
+            parameters: Vec::new(),
+            returns: None,
+            elements: Elements::new(),
+            export: false,
+        }
+    }
+
+    pub fn export(&mut self) {
+        self.export = true;
+    }
+
+    pub fn push_type_parameter<T>(&mut self, parameter: T)
+    where
+        T: Into<TypeParameter>,
+    {
+        self.type_parameters.push(parameter.into());
+    }
+
+    pub fn push_parameter<P>(&mut self, parameter: P)
+    where
+        P: Into<ParameterSpec>,
+    {
+        self.parameters.push(parameter.into());
+    }
+
+    pub fn returns<T>(&mut self, returns: T)
+    where
+        T: Into<Type>,
+    {
+        self.returns = Some(returns.into());
+    }
+
+    pub fn push<E>(&mut self, element: E)
+    where
+        E: Into<Element>,
+    {
+        self.elements.push(element);
+    }
+}
+
+impl From<FunctionSpec> for Element {
+    fn from(value: FunctionSpec) -> Element {
+        let mut open = Statement::new();
+
+        if value.export {
+            open.push("export ");
+        }
+
+        open.push("function ");
+        open.push(value.name);
+        open.push(type_parameters(value.type_parameters));
+        open.push(signature(value.parameters, value.returns));
+        open.push(" {");
+
+        let mut out = Elements::new();
+        out.push(open);
+        out.push_nested(value.elements.join(Spacing));
+        out.push("}");
+
+        out.into()
+    }
+}
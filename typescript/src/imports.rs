@@ -0,0 +1,111 @@
+use codeviz_common::Walk;
+use super::*;
+
+pub trait ImportReceiver {
+    fn receive(&mut self, name: &ImportedName);
+
+    /// Receive a name which is only referenced as a type.
+    ///
+    /// Names which are only referenced as types can be imported with `import type`.
+    fn receive_type(&mut self, name: &ImportedName) {
+        self.receive(name);
+    }
+
+    fn import_all<T>(&mut self, sources: &Vec<T>)
+    where
+        T: Imports,
+        Self: Sized,
+    {
+        for source in sources {
+            source.imports(self);
+        }
+    }
+}
+
+pub trait Imports {
+    fn imports<I>(&self, receiver: &mut I)
+    where
+        I: ImportReceiver;
+}
+
+impl Imports for Name {
+    fn imports<I>(&self, receiver: &mut I)
+    where
+        I: ImportReceiver,
+    {
+        if let Name::Imported(ref imported) = *self {
+            receiver.receive(imported);
+        }
+    }
+}
+
+impl Imports for Type {
+    fn imports<I>(&self, receiver: &mut I)
+    where
+        I: ImportReceiver,
+    {
+        match *self {
+            Type::Name(Name::Imported(ref imported)) => {
+                receiver.receive_type(imported);
+            }
+            Type::Generic(ref base, ref arguments) => {
+                base.imports(receiver);
+                receiver.import_all(arguments);
+            }
+            Type::Array(ref inner) => {
+                inner.imports(receiver);
+            }
+            Type::Union(ref types) | Type::Intersection(ref types) => {
+                receiver.import_all(types);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Imports for Variable {
+    fn imports<I>(&self, receiver: &mut I)
+    where
+        I: ImportReceiver,
+    {
+        match *self {
+            Variable::Statement(ref stmt) => {
+                stmt.imports(receiver);
+            }
+            Variable::Name(ref name) => {
+                name.imports(receiver);
+            }
+            Variable::Type(ref ty) => {
+                ty.imports(receiver);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Imports for Statement {
+    fn imports<I>(&self, receiver: &mut I)
+    where
+        I: ImportReceiver,
+    {
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
+    }
+}
+
+impl Imports for Element {
+    fn imports<I>(&self, receiver: &mut I)
+    where
+        I: ImportReceiver,
+    {
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
+    }
+}
+
+impl Imports for Elements {
+    fn imports<I>(&self, receiver: &mut I)
+    where
+        I: ImportReceiver,
+    {
+        self.walk(&mut |variable: &Variable| variable.imports(receiver));
+    }
+}
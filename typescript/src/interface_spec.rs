@@ -0,0 +1,98 @@
+use super::*;
+
+/// An interface declaration, made up of properties and method signatures.
+#[derive(Debug, Clone)]
+pub struct InterfaceSpec {
+    pub name: String,
+    pub type_parameters: Vec<TypeParameter>,
+    pub extends: Vec<Type>,
+    pub properties: Vec<PropertySpec>,
+    pub methods: Vec<MethodSpec>,
+    pub export: bool,
+}
+
+impl InterfaceSpec {
+    pub fn new(name: &str) -> InterfaceSpec {
+        InterfaceSpec {
+            name: name.to_owned(),
+            type_parameters: Vec::new(),
+            extends: Vec::new(),
+            properties: Vec::new(),
+            methods: Vec::new(),
+            export: false,
+        }
+    }
+
+    pub fn export(&mut self) {
+        self.export = true;
+    }
+
+    pub fn push_type_parameter<T>(&mut self, parameter: T)
+    where
+        T: Into<TypeParameter>,
+    {
+        self.type_parameters.push(parameter.into());
+    }
+
+    pub fn extends<T>(&mut self, extends: T)
+    where
+        T: Into<Type>,
+    {
+        self.extends.push(extends.into());
+    }
+
+    pub fn push_property<P>(&mut self, property: P)
+    where
+        P: Into<PropertySpec>,
+    {
+        self.properties.push(property.into());
+    }
+
+    /// Push a method signature, any body of the method is ignored.
+    pub fn push_method<M>(&mut self, method: M)
+    where
+        M: Into<MethodSpec>,
+    {
+        self.methods.push(method.into());
+    }
+}
+
+impl From<InterfaceSpec> for Element {
+    fn from(value: InterfaceSpec) -> Element {
+        let mut open = Statement::new();
+
+        if value.export {
+            open.push("export ");
+        }
+
+        open.push("interface ");
+        open.push(value.name);
+        open.push(type_parameters(value.type_parameters));
+
+        if !value.extends.is_empty() {
+            open.push(" extends ");
+            open.push(join_types(value.extends, ", "));
+        }
+
+        open.push(" {");
+
+        let mut body = Elements::new();
+
+        for property in value.properties {
+            body.push(property);
+        }
+
+        for method in value.methods {
+            let mut signature = method.declaration();
+            signature.push(";");
+            body.push(signature);
+        }
+
+        let mut out = Elements::new();
+        out.push(open);
+        out.push_nested(body);
+        out.push("}");
+
+        out.into()
+    }
+}
@@ -0,0 +1,55 @@
+extern crate codeviz_common;
+extern crate codeviz_js;
+
+mod _type;
+mod class_spec;
+mod common;
+mod constructor_spec;
+mod enum_spec;
+mod file_spec;
+mod function_spec;
+mod imports;
+mod interface_spec;
+mod method_spec;
+mod parameter_spec;
+mod property_spec;
+mod type_alias_spec;
+mod type_parameter;
+mod variable;
+mod visitor;
+
+pub use codeviz_common::Element::*;
+pub use codeviz_js::{BareResolver, BuiltInName, Extension, ImportKind, ImportedName, LocalName,
+                     ModuleImports, ModuleResolver, Name, PackageResolver, PathResolver,
+                     RelativeResolver};
+pub use self::_type::*;
+pub use self::class_spec::*;
+pub use self::constructor_spec::*;
+pub use self::enum_spec::*;
+pub use self::file_spec::*;
+pub use self::function_spec::*;
+pub use self::imports::*;
+pub use self::interface_spec::*;
+pub use self::method_spec::*;
+pub use self::parameter_spec::*;
+pub use self::property_spec::*;
+pub use self::type_alias_spec::*;
+pub use self::type_parameter::*;
+pub use self::variable::*;
+pub use self::visitor::*;
+pub(crate) use self::common::*;
+pub(crate) use self::errors::*;
+
+pub mod errors {
+    pub use codeviz_common::errors::*;
+}
+
+pub type Element = codeviz_common::Element<Variable>;
+pub type Elements = codeviz_common::Elements<Variable>;
+pub type Statement = codeviz_common::Statement<Variable>;
+
+impl From<Variable> for Statement {
+    fn from(value: Variable) -> Statement {
+        Statement { parts: vec![value] }
+    }
+}
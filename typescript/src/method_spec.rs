@@ -0,0 +1,89 @@
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct MethodSpec {
+    pub name: String,
+    pub type_parameters: Vec<TypeParameter>,
+    pub parameters: Vec<ParameterSpec>,
+    pub returns: Option<Type>,
+    pub elements: Elements,
+    pub is_static: bool,
+}
+
+impl MethodSpec {
+    pub fn new(name: &str) -> MethodSpec {
+        MethodSpec {
+            name: name.to_owned(),
+            type_parameters: Vec::new(),
+            parameters: Vec::new(),
+            returns: None,
+            elements: Elements::new(),
+            is_static: false,
+        }
+    }
+
+    pub fn with_static(name: &str) -> MethodSpec {
+        MethodSpec {
+            is_static: true,
+            ..MethodSpec::new(name)
+        }
+    }
+
+    pub fn push_type_parameter<T>(&mut self, parameter: T)
+    where
+        T: Into<TypeParameter>,
+    {
+        self.type_parameters.push(parameter.into());
+    }
+
+    pub fn push_parameter<P>(&mut self, parameter: P)
+    where
+        P: Into<ParameterSpec>,
+    {
+        self.parameters.push(parameter.into());
+    }
+
+    pub fn returns<T>(&mut self, returns: T)
+    where
+        T: Into<Type>,
+    {
+        self.returns = Some(returns.into());
+    }
+
+    pub fn push<E>(&mut self, element: E)
+    where
+        E: Into<Element>,
+    {
+        self.elements.push(element);
+    }
+
+    /// Build the declaration of this method, without a body, like `name(a: string): void`.
+    pub fn declaration(self) -> Statement {
+        let mut out = Statement::new();
+
+        if self.is_static {
+            out.push("static ");
+        }
+
+        out.push(self.name);
+        out.push(type_parameters(self.type_parameters));
+        out.push(signature(self.parameters, self.returns));
+        out
+    }
+}
+
+impl From<MethodSpec> for Element {
+    fn from(value: MethodSpec) -> Element {
+        let elements = value.elements.clone();
+
+        let mut open = value.declaration();
+        open.push(" {");
+
+        let mut out = Elements::new();
+        out.push(open);
+        out.push_nested(elements.join(Spacing));
+        out.push("}");
+
+        out.into()
+    }
+}
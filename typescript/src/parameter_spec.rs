@@ -0,0 +1,82 @@
+use super::*;
+
+/// A parameter of a function, method, or constructor, like `name?: string`.
+#[derive(Debug, Clone)]
+pub struct ParameterSpec {
+    pub name: String,
+    pub ty: Option<Type>,
+    pub optional: bool,
+    pub default: Option<Statement>,
+}
+
+impl ParameterSpec {
+    pub fn new(name: &str) -> ParameterSpec {
+        ParameterSpec {
+            name: name.to_owned(),
+            ty: None,
+            optional: false,
+            default: None,
+        }
+    }
+
+    pub fn with_type<T>(name: &str, ty: T) -> ParameterSpec
+    where
+        T: Into<Type>,
+    {
+        ParameterSpec {
+            name: name.to_owned(),
+            ty: Some(ty.into()),
+            optional: false,
+            default: None,
+        }
+    }
+
+    pub fn optional(&mut self) {
+        self.optional = true;
+    }
+
+    pub fn default<S>(&mut self, default: S)
+    where
+        S: Into<Statement>,
+    {
+        self.default = Some(default.into());
+    }
+}
+
+impl<'a, T> From<&'a T> for ParameterSpec
+where
+    T: Into<ParameterSpec> + Clone,
+{
+    fn from(value: &'a T) -> ParameterSpec {
+        value.clone().into()
+    }
+}
+
+impl<'a> From<&'a str> for ParameterSpec {
+    fn from(value: &'a str) -> ParameterSpec {
+        ParameterSpec::new(value)
+    }
+}
+
+impl From<ParameterSpec> for Statement {
+    fn from(value: ParameterSpec) -> Statement {
+        let mut out = Statement::new();
+        out.push(value.name);
+
+        if value.optional {
+            out.push("?");
+        }
+
+        if let Some(ty) = value.ty {
+            out.push(": ");
+            out.push(ty);
+        }
+
+        if let Some(default) = value.default {
+            out.push(" = ");
+            out.push(default);
+        }
+
+        out
+    }
+}
@@ -0,0 +1,107 @@
+use super::*;
+
+/// A property of a class or interface, like `readonly name?: string;`.
+#[derive(Debug, Clone)]
+pub struct PropertySpec {
+    pub name: String,
+    pub ty: Option<Type>,
+    pub optional: bool,
+    pub readonly: bool,
+    pub is_static: bool,
+    pub initializer: Option<Statement>,
+}
+
+impl PropertySpec {
+    pub fn new<T>(name: &str, ty: T) -> PropertySpec
+    where
+        T: Into<Type>,
+    {
+        PropertySpec {
+            name: name.to_owned(),
+            ty: Some(ty.into()),
+            optional: false,
+            readonly: false,
+            is_static: false,
+            initializer: None,
+        }
+    }
+
+    /// A property without a type annotation, which has its type inferred from its initializer.
+    pub fn untyped(name: &str) -> PropertySpec {
+        PropertySpec {
+            name: name.to_owned(),
+            ty: None,
+            optional: false,
+            readonly: false,
+            is_static: false,
+            initializer: None,
+        }
+    }
+
+    pub fn optional(&mut self) {
+        self.optional = true;
+    }
+
+    pub fn readonly(&mut self) {
+        self.readonly = true;
+    }
+
+    pub fn with_static(&mut self) {
+        self.is_static = true;
+    }
+
+    pub fn initializer<S>(&mut self, initializer: S)
+    where
+        S: Into<Statement>,
+    {
+        self.initializer = Some(initializer.into());
+    }
+}
+
+impl<'a, T> From<&'a T> for PropertySpec
+where
+    T: Into<PropertySpec> + Clone,
+{
+    fn from(value: &'a T) -> PropertySpec {
+        value.clone().into()
+    }
+}
+
+impl From<PropertySpec> for Statement {
+    fn from(value: PropertySpec) -> Statement {
+        let mut out = Statement::new();
+
+        if value.is_static {
+            out.push("static ");
+        }
+
+        if value.readonly {
+            out.push("readonly ");
+        }
+
+        out.push(value.name);
+
+        if value.optional {
+            out.push("?");
+        }
+
+        if let Some(ty) = value.ty {
+            out.push(": ");
+            out.push(ty);
+        }
+
+        if let Some(initializer) = value.initializer {
+            out.push(" = ");
+            out.push(initializer);
+        }
+
+        out.push(";");
+        out
+    }
+}
+
+impl From<PropertySpec> for Element {
+    fn from(value: PropertySpec) -> Element {
+        Push(value.into())
+    }
+}
@@ -0,0 +1,54 @@
+use super::*;
+
+/// A type alias, like `type Id = string | number;`.
+#[derive(Debug, Clone)]
+pub struct TypeAliasSpec {
+    pub name: String,
+    pub type_parameters: Vec<TypeParameter>,
+    pub ty: Type,
+    pub export: bool,
+}
+
+impl TypeAliasSpec {
+    pub fn new<T>(name: &str, ty: T) -> TypeAliasSpec
+    where
+        T: Into<Type>,
+    {
+        TypeAliasSpec {
+            name: name.to_owned(),
+            type_parameters: Vec::new(),
+            ty: ty.into(),
+            export: false,
+        }
+    }
+
+    pub fn export(&mut self) {
+        self.export = true;
+    }
+
+    pub fn push_type_parameter<T>(&mut self, parameter: T)
+    where
+        T: Into<TypeParameter>,
+    {
+        self.type_parameters.push(parameter.into());
+    }
+}
+
+impl From<TypeAliasSpec> for Element {
+    fn from(value: TypeAliasSpec) -> Element {
+        let mut out = Statement::new();
+
+        if value.export {
+            out.push("export ");
+        }
+
+        out.push("type ");
+        out.push(value.name);
+        out.push(type_parameters(value.type_parameters));
+        out.push(" = ");
+        out.push(value.ty);
+        out.push(";");
+
+        Push(out)
+    }
+}
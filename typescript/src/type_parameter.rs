@@ -0,0 +1,67 @@
+use super::*;
+
+/// A type parameter of a generic declaration, like `T extends Foo = Bar`.
+#[derive(Debug, Clone)]
+pub struct TypeParameter {
+    pub name: String,
+    pub constraint: Option<Type>,
+    pub default: Option<Type>,
+}
+
+impl TypeParameter {
+    pub fn new(name: &str) -> TypeParameter {
+        TypeParameter {
+            name: name.to_owned(),
+            constraint: None,
+            default: None,
+        }
+    }
+
+    pub fn constraint<T>(&mut self, constraint: T)
+    where
+        T: Into<Type>,
+    {
+        self.constraint = Some(constraint.into());
+    }
+
+    pub fn default<T>(&mut self, default: T)
+    where
+        T: Into<Type>,
+    {
+        self.default = Some(default.into());
+    }
+}
+
+impl<'a, T> From<&'a T> for TypeParameter
+where
+    T: Into<TypeParameter> + Clone,
+{
+    fn from(value: &'a T) -> TypeParameter {
+        value.clone().into()
+    }
+}
+
+impl<'a> From<&'a str> for TypeParameter {
+    fn from(value: &'a str) -> TypeParameter {
+        TypeParameter::new(value)
+    }
+}
+
+impl From<TypeParameter> for Statement {
+    fn from(value: TypeParameter) -> Statement {
+        let mut out = Statement::new();
+        out.push(value.name);
+
+        if let Some(constraint) = value.constraint {
+            out.push(" extends ");
+            out.push(constraint);
+        }
+
+        if let Some(default) = value.default {
+            out.push(" = ");
+            out.push(default);
+        }
+
+        out
+    }
+}
@@ -0,0 +1,99 @@
+use super::*;
+use codeviz_common::ElementFormat;
+use codeviz_common::VariableFormat;
+use codeviz_js::quote_string;
+
+/// Variables that are part of statements.
+#[derive(Debug, Clone)]
+pub enum Variable {
+    /// String that will be literally appended.
+    Literal(String),
+    /// String that will be quoted and appended.
+    String(String),
+    /// Another statement that will be appended.
+    Statement(Statement),
+    /// A name that will be appended.
+    Name(Name),
+    /// A type that will be appended.
+    Type(Type),
+}
+
+impl VariableFormat for Variable {
+    type Extra = ();
+
+    fn format<E>(&self, out: &mut E, depth: usize, extra: &mut ()) -> Result<()>
+    where
+        E: ElementFormat,
+    {
+        match *self {
+            Variable::String(ref string) => {
+                quote_string(out, string)?;
+            }
+            Variable::Statement(ref stmt) => {
+                stmt.format(out, depth, extra)?;
+            }
+            Variable::Literal(ref content) => {
+                out.write_str(content)?;
+            }
+            Variable::Name(ref name) => {
+                name.format(out)?;
+            }
+            Variable::Type(ref ty) => {
+                ty.format(out)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, A> From<&'a A> for Variable
+where
+    A: Into<Variable> + Clone,
+{
+    fn from(value: &'a A) -> Variable {
+        value.clone().into()
+    }
+}
+
+impl<'a> From<&'a str> for Variable {
+    fn from(value: &'a str) -> Variable {
+        Variable::Literal(value.to_owned())
+    }
+}
+
+impl From<String> for Variable {
+    fn from(value: String) -> Variable {
+        Variable::Literal(value)
+    }
+}
+
+impl From<Statement> for Variable {
+    fn from(value: Statement) -> Variable {
+        Variable::Statement(value)
+    }
+}
+
+impl From<Name> for Variable {
+    fn from(value: Name) -> Variable {
+        Variable::Name(value)
+    }
+}
+
+impl From<ImportedName> for Variable {
+    fn from(value: ImportedName) -> Variable {
+        Variable::Name(value.into())
+    }
+}
+
+impl From<BuiltInName> for Variable {
+    fn from(value: BuiltInName) -> Variable {
+        Variable::Name(value.into())
+    }
+}
+
+impl From<LocalName> for Variable {
+    fn from(value: LocalName) -> Variable {
+        Variable::Name(value.into())
+    }
+}
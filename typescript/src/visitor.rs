@@ -0,0 +1,83 @@
+use codeviz_common::{Visitor, VisitorMut};
+use super::*;
+
+/// Walk the children of a variable, like nested statements and the names of a type.
+///
+/// Names in types are visited as `Variable::Name`.
+pub fn walk_variable<V>(visitor: &mut V, variable: &Variable)
+where
+    V: Visitor<Variable> + ?Sized,
+{
+    match *variable {
+        Variable::Statement(ref stmt) => visitor.visit_statement(stmt),
+        Variable::Type(ref ty) => walk_type(visitor, ty),
+        Variable::Literal(_) | Variable::String(_) | Variable::Name(_) => {}
+    }
+}
+
+/// Walk the children of a variable, permitting them to be rewritten.
+///
+/// See `walk_variable`.
+pub fn walk_variable_mut<V>(visitor: &mut V, variable: &mut Variable)
+where
+    V: VisitorMut<Variable> + ?Sized,
+{
+    match *variable {
+        Variable::Statement(ref mut stmt) => visitor.visit_statement_mut(stmt),
+        Variable::Type(ref mut ty) => walk_type_mut(visitor, ty),
+        Variable::Literal(_) | Variable::String(_) | Variable::Name(_) => {}
+    }
+}
+
+fn walk_type<V>(visitor: &mut V, ty: &Type)
+where
+    V: Visitor<Variable> + ?Sized,
+{
+    match *ty {
+        Type::Name(ref name) => visitor.visit_variable(&Variable::Name(name.clone())),
+        Type::Generic(ref base, ref arguments) => {
+            walk_type(visitor, base);
+
+            for argument in arguments {
+                walk_type(visitor, argument);
+            }
+        }
+        Type::Array(ref inner) => walk_type(visitor, inner),
+        Type::Union(ref types) | Type::Intersection(ref types) => {
+            for ty in types {
+                walk_type(visitor, ty);
+            }
+        }
+        Type::StringLiteral(_) | Type::Literal(_) => {}
+    }
+}
+
+fn walk_type_mut<V>(visitor: &mut V, ty: &mut Type)
+where
+    V: VisitorMut<Variable> + ?Sized,
+{
+    match *ty {
+        Type::Name(ref mut name) => {
+            let mut variable = Variable::Name(name.clone());
+            visitor.visit_variable_mut(&mut variable);
+
+            if let Variable::Name(rewritten) = variable {
+                *name = rewritten;
+            }
+        }
+        Type::Generic(ref mut base, ref mut arguments) => {
+            walk_type_mut(visitor, base);
+
+            for argument in arguments {
+                walk_type_mut(visitor, argument);
+            }
+        }
+        Type::Array(ref mut inner) => walk_type_mut(visitor, inner),
+        Type::Union(ref mut types) | Type::Intersection(ref mut types) => {
+            for ty in types {
+                walk_type_mut(visitor, ty);
+            }
+        }
+        Type::StringLiteral(_) | Type::Literal(_) => {}
+    }
+}